@@ -0,0 +1,137 @@
+//! Baseline timings for the secp256k1 point arithmetic.
+//!
+//! Run with `cargo bench -p signatures`.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use num_bigint::BigUint;
+use signatures::elliptic_curves::{
+    arithmetic::add_two_points, comb::GeneratorComb, curve::Curve, generator::Generator,
+    p256::P256, secp256k1::Secp256k1,
+};
+
+/// A fixed 256-bit scalar, so every run does the same amount of work
+fn scalar() -> BigUint {
+    BigUint::parse_bytes(
+        b"67e92d78fd7630b2a2ef6071de5134d18af00a133fad517d86cc7763222724a3",
+        16,
+    )
+    .unwrap()
+}
+
+fn bench_calculate_public_key(c: &mut Criterion) {
+    let curve = Secp256k1;
+    let secret = scalar();
+
+    c.bench_function("secp256k1/calculate_public_key", |b| {
+        b.iter(|| curve.calculate_public_key(black_box(secret.clone())))
+    });
+}
+
+/// Fixed-base multiplication with precomputed tables, compared against `calculate_public_key` above
+fn bench_generator_comb(c: &mut Criterion) {
+    let secret = scalar();
+
+    let mut group = c.benchmark_group("secp256k1/generator_comb");
+    for teeth in [4, 6] {
+        let comb = GeneratorComb::new(Secp256k1, teeth);
+        group.bench_function(format!("{teeth}_teeth"), |b| {
+            b.iter(|| comb.mul(black_box(&secret)))
+        });
+    }
+    group.finish();
+}
+
+/// Borrowing the cached generator and its multiples, against rebuilding them (P-256 parses its generator on
+/// every call)
+fn bench_generator(c: &mut Criterion) {
+    let curve = P256;
+    let generator = Generator::new(P256, 8);
+
+    let mut group = c.benchmark_group("p256/generator");
+    group.bench_function("recomputed", |b| {
+        b.iter(|| black_box(&curve).generator_point())
+    });
+    group.bench_function("cached", |b| {
+        b.iter(|| black_box(&generator).point().clone())
+    });
+    group.bench_function("recomputed_multiple", |b| {
+        b.iter(|| curve.calculate_public_key(black_box(BigUint::from(7_u8))))
+    });
+    group.bench_function("cached_multiple", |b| {
+        b.iter(|| black_box(&generator).multiple(7).cloned())
+    });
+    group.finish();
+}
+
+fn bench_add_two_points(c: &mut Criterion) {
+    let curve = Secp256k1;
+    let generator = curve.generator_point();
+    let doubled = curve.calculate_public_key(BigUint::from(2_u8));
+
+    let mut group = c.benchmark_group("secp256k1/add_two_points");
+    group.bench_function("addition", |b| {
+        b.iter(|| {
+            add_two_points(
+                black_box(generator.clone()),
+                black_box(doubled.clone()),
+                &curve,
+            )
+        })
+    });
+    group.bench_function("doubling", |b| {
+        b.iter(|| {
+            add_two_points(
+                black_box(generator.clone()),
+                black_box(generator.clone()),
+                &curve,
+            )
+        })
+    });
+    group.finish();
+}
+
+fn bench_scalar_mul(c: &mut Criterion) {
+    let curve = Secp256k1;
+    let point = curve.calculate_public_key(BigUint::from(3_u8));
+    let scalar = scalar();
+
+    c.bench_function("secp256k1/scalar_mul", |b| {
+        b.iter(|| curve.scalar_mul(black_box(&scalar), black_box(point.clone())))
+    });
+}
+
+/// The bit scan that drives `scalar_mul`, isolated from the point arithmetic
+fn bench_scalar_bit_scan(c: &mut Criterion) {
+    let scalar = scalar();
+    let one = BigUint::from(1_u8);
+
+    let mut group = c.benchmark_group("scalar_bit_scan");
+    group.bench_function("shift_clone", |b| {
+        b.iter(|| {
+            (0..scalar.bits())
+                .filter(|&i| (black_box(&scalar).clone() >> i) & &one == one)
+                .count()
+        })
+    });
+    group.bench_function("bit", |b| {
+        b.iter(|| {
+            (0..scalar.bits())
+                .filter(|&i| black_box(&scalar).bit(i))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_calculate_public_key,
+    bench_generator_comb,
+    bench_generator,
+    bench_add_two_points,
+    bench_scalar_mul,
+    bench_scalar_bit_scan
+);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+//! End-to-end ECDSA over secp256k1: generate a keypair, sign a message, print the DER signature and the
+//! compressed public key, then verify the signature from those serialized forms alone.
+//!
+//! Run with `cargo run --example sign_verify`.
+
+use num_bigint::BigUint;
+use sha2::Sha256;
+use signatures::{
+    digital_signature::Signature,
+    ecdsa::{hash_message, verify_with_pubkey_bytes, EcdsaSignature},
+    elliptic_curves::{curve::CurvePoint, secp256k1::Secp256k1},
+    error::Error,
+};
+
+/// What a signer hands to a verifier, and whether the verifier accepted it
+struct Transcript {
+    public_key_hex: String,
+    signature_der_hex: String,
+    valid: bool,
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn run(message: &[u8]) -> Result<Transcript, Error> {
+    let mut keypair = Signature {
+        curve: Secp256k1,
+        secret: BigUint::ZERO,
+        public_key: CurvePoint::Infinity,
+    };
+    keypair.generate_keypair();
+
+    let der = keypair.sign_message::<Sha256>(message).to_der();
+    let public_key = keypair.compressed_public_key();
+
+    // The verifier only sees the message and the serialized signature and key
+    let signature = EcdsaSignature::from_der(&der, &Secp256k1)?;
+    let message_hash = hash_message::<Sha256, _>(message, &Secp256k1);
+    let valid = verify_with_pubkey_bytes(&message_hash, &signature, &public_key, Secp256k1)?;
+
+    Ok(Transcript {
+        public_key_hex: keypair.compressed_public_key_hex(),
+        signature_der_hex: to_hex(&der),
+        valid,
+    })
+}
+
+fn main() -> Result<(), Error> {
+    let message = b"hello from shai";
+    let transcript = run(message)?;
+
+    println!("message:    {}", String::from_utf8_lossy(message));
+    println!("public key: {}", transcript.public_key_hex);
+    println!("signature:  {}", transcript.signature_der_hex);
+    println!("valid:      {}", transcript.valid);
+
+    if !transcript.valid {
+        return Err(Error::InvalidSignature);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+
+    #[test]
+    fn should_sign_and_verify_end_to_end() {
+        let transcript = run(b"hello from shai").unwrap();
+
+        assert!(transcript.valid);
+        assert_eq!(transcript.public_key_hex.len(), 66);
+        assert!(["02", "03"].contains(&&transcript.public_key_hex[..2]));
+        assert!(transcript.signature_der_hex.starts_with("30"));
+    }
+}
@@ -0,0 +1,119 @@
+use alloc::{string::String, vec::Vec};
+
+use num_bigint::BigUint;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+use crate::{elliptic_curves::secp256k1::Secp256k1, public_key::PublicKey};
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Bitcoin network an address is intended for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+}
+
+impl Network {
+    /// Returns the P2PKH version byte of the network
+    pub fn p2pkh_version(&self) -> u8 {
+        match self {
+            Network::Mainnet => 0x00,
+            Network::Testnet => 0x6f,
+        }
+    }
+}
+
+/// Derives the legacy (P2PKH) Bitcoin address of a secp256k1 public key.
+///
+/// Taking a [`PublicKey`] rather than a bare point rules out encoding the point at infinity or a point off
+/// the curve into an address nobody can spend from.
+///
+/// 1. Encode the public key in compressed SEC1 form.
+/// 2. Hash it with SHA-256 and then RIPEMD-160 (`HASH160`).
+/// 3. Prepend the network's version byte.
+/// 4. Base58Check-encode the result.
+pub fn to_btc_address(public_key: &PublicKey<Secp256k1>, network: Network) -> String {
+    let hash160 = Ripemd160::digest(Sha256::digest(public_key.to_sec1(true)));
+
+    let mut payload = Vec::with_capacity(1 + hash160.len());
+    payload.push(network.p2pkh_version());
+    payload.extend_from_slice(&hash160);
+    base58check_encode(&payload)
+}
+
+/// Appends the first four bytes of `SHA256(SHA256(payload))` as a checksum and Base58-encodes it.
+pub fn base58check_encode(payload: &[u8]) -> String {
+    let checksum = Sha256::digest(Sha256::digest(payload));
+
+    let mut bytes = Vec::with_capacity(payload.len() + 4);
+    bytes.extend_from_slice(payload);
+    bytes.extend_from_slice(&checksum[..4]);
+    base58_encode(&bytes)
+}
+
+/// Encodes bytes in Base58 using the Bitcoin alphabet.
+///
+/// The input is treated as a big-endian integer and written in base 58. Each leading zero byte is
+/// encoded as a leading `1`, since it would otherwise be lost in the integer conversion.
+pub fn base58_encode(bytes: &[u8]) -> String {
+    let leading_zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+
+    let mut digits = BigUint::from_bytes_be(bytes).to_radix_be(58);
+    if digits == [0] {
+        digits.clear();
+    }
+
+    let mut encoded = String::with_capacity(leading_zeros + digits.len());
+    encoded.extend(core::iter::repeat_n('1', leading_zeros));
+    encoded.extend(
+        digits
+            .into_iter()
+            .map(|digit| BASE58_ALPHABET[digit as usize] as char),
+    );
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::{base58_encode, to_btc_address, Network};
+    use crate::{
+        elliptic_curves::{curve::Curve, secp256k1::Secp256k1},
+        public_key::PublicKey,
+    };
+
+    #[test]
+    fn should_encode_base58_vectors() {
+        let vectors: [(&[u8], &str); 6] = [
+            (b"", ""),
+            (b"a", "2g"),
+            (b"bbb", "a3gV"),
+            (b"ccc", "aPEr"),
+            (b"simply a long string", "2cFupjhnEsSn59qHXstmK2ffpLv2"),
+            (&[0; 10], "1111111111"),
+        ];
+
+        for (bytes, expected) in vectors {
+            assert_eq!(base58_encode(bytes), expected);
+        }
+    }
+
+    #[test]
+    fn should_derive_address_of_known_key() {
+        // The compressed public key of the secret key 1 is the generator
+        let point = Secp256k1.calculate_public_key(BigUint::from(1_u8));
+        let public_key = PublicKey::new(Secp256k1, point).unwrap();
+
+        assert_eq!(
+            to_btc_address(&public_key, Network::Mainnet),
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"
+        );
+        assert_eq!(
+            to_btc_address(&public_key, Network::Testnet),
+            "mrCDrCybB6J1vRfbwM5hemdJz73FwDBC8r"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! The small subset of DER (ITU-T X.690) needed for ECDSA signatures and SEC1 private keys.
+//!
+//! Lengths are limited to 255 bytes, which covers both for every supported curve.
+
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+
+pub(crate) const INTEGER: u8 = 0x02;
+pub(crate) const BIT_STRING: u8 = 0x03;
+pub(crate) const OCTET_STRING: u8 = 0x04;
+pub(crate) const OBJECT_IDENTIFIER: u8 = 0x06;
+pub(crate) const SEQUENCE: u8 = 0x30;
+
+/// Returns the tag of the context-specific, constructed element `[number]`
+pub(crate) const fn context_tag(number: u8) -> u8 {
+    0xa0 | number
+}
+
+/// Encodes `content` as an element with the given tag
+pub(crate) fn element(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(3 + content.len());
+    bytes.push(tag);
+    push_length(&mut bytes, content.len());
+    bytes.extend_from_slice(content);
+    bytes
+}
+
+/// Encodes a non-negative integer in as few bytes as possible, with a leading zero byte when its top bit is set
+/// (DER integers are signed)
+pub(crate) fn integer(value: &BigUint) -> Vec<u8> {
+    let mut content = value.to_bytes_be();
+    if content[0] & 0x80 != 0 {
+        content.insert(0, 0);
+    }
+    element(INTEGER, &content)
+}
+
+/// Appends a length, which takes a second byte from 128 on (e.g. for P-384 private keys)
+fn push_length(bytes: &mut Vec<u8>, length: usize) {
+    debug_assert!(length <= 0xff, "DER elements are at most 255 bytes long");
+    if length < 0x80 {
+        bytes.push(length as u8);
+    } else {
+        bytes.extend_from_slice(&[0x81, length as u8]);
+    }
+}
+
+/// Splits off the content of an element with the given tag, returning it and the bytes after it.
+///
+/// # Returns
+/// - `None` if `bytes` doesn't start with such an element, or its length isn't minimally encoded.
+pub(crate) fn read_element(bytes: &[u8], tag: u8) -> Option<(&[u8], &[u8])> {
+    let (length, rest) = match bytes {
+        [t, length, rest @ ..] if *t == tag && *length < 0x80 => (*length, rest),
+        // The long form is only allowed for lengths the short form can't express
+        [t, 0x81, length, rest @ ..] if *t == tag && *length >= 0x80 => (*length, rest),
+        _ => return None,
+    };
+    if rest.len() < usize::from(length) {
+        return None;
+    }
+    Some(rest.split_at(usize::from(length)))
+}
+
+/// Splits off a non-negative integer, see [`integer`].
+///
+/// # Returns
+/// - `None` if `bytes` doesn't start with an integer, or the integer is negative or not minimally encoded.
+pub(crate) fn read_integer(bytes: &[u8]) -> Option<(BigUint, &[u8])> {
+    let (content, rest) = read_element(bytes, INTEGER)?;
+    match content {
+        [] => None,
+        // Negative
+        [first, ..] if first & 0x80 != 0 => None,
+        // Not minimal: the leading zero is only needed before a set top bit
+        [0, second, ..] if second & 0x80 == 0 => None,
+        _ => Some((BigUint::from_bytes_be(content), rest)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use num_bigint::BigUint;
+
+    use super::{context_tag, element, integer, read_element, read_integer, OCTET_STRING};
+
+    #[test]
+    fn should_switch_to_long_form_length_from_128_bytes() {
+        for (length, header) in [(0x7f, vec![0x04, 0x7f]), (0x80, vec![0x04, 0x81, 0x80])] {
+            let content = vec![0xab; length];
+            let bytes = element(OCTET_STRING, &content);
+            assert_eq!(bytes[..header.len()], header);
+            assert_eq!(
+                read_element(&bytes, OCTET_STRING),
+                Some((content.as_slice(), [].as_slice()))
+            );
+        }
+
+        // A long form length below 128 is not minimal
+        assert_eq!(read_element(&[0x04, 0x81, 0x01, 0x00], OCTET_STRING), None);
+        assert_eq!(read_element(&[0x04, 0x02, 0x00], OCTET_STRING), None);
+    }
+
+    #[test]
+    fn should_round_trip_integers() {
+        for value in [0_u32, 1, 0x7f, 0x80, 0xffff] {
+            let value = BigUint::from(value);
+            let bytes = integer(&value);
+            assert_eq!(read_integer(&bytes), Some((value, [].as_slice())));
+        }
+        assert_eq!(integer(&BigUint::from(0x80_u8)), [0x02, 0x02, 0x00, 0x80]);
+    }
+
+    #[test]
+    fn should_build_context_tags() {
+        assert_eq!(context_tag(0), 0xa0);
+        assert_eq!(context_tag(1), 0xa1);
+    }
+}
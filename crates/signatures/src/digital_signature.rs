@@ -1,18 +1,539 @@
+use alloc::{format, string::String, vec, vec::Vec};
+
 use num_bigint::BigUint;
+#[cfg(feature = "std")]
+use rand::thread_rng;
+use rand::RngCore;
 
-use crate::elliptic_curves::curve::Curve;
+use crate::{
+    der,
+    elliptic_curves::{
+        curve::{hash_to_secret, Curve, CurvePoint},
+        scalar::scalar_to_be_bytes,
+        sec1::{from_sec1, to_sec1},
+    },
+    error::Error,
+};
 
 pub struct Signature<T: Curve> {
     pub curve: T,
     pub secret: BigUint,
-    pub public_key: BigUint,
+    pub public_key: CurvePoint,
 }
 
 impl<T: Curve> Signature<T> {
     /// Generates a new keypair, if not already present
+    #[cfg(feature = "std")]
     pub fn generate_keypair(&mut self) -> &mut Self {
-        self.secret = self.curve.generate_secret_key();
-        // self.public_key = self.secret.mod
+        self.generate_keypair_with_rng(&mut thread_rng())
+    }
+
+    /// Generates a new keypair using the given random number generator
+    pub fn generate_keypair_with_rng<R: RngCore + ?Sized>(&mut self, rng: &mut R) -> &mut Self {
+        self.secret = self.curve.generate_secret_key_with_rng(rng);
+        self.public_key = self.curve.calculate_public_key(self.secret.clone());
         self
     }
+
+    /// Wipes the keypair, leaving the keyless state a fresh `generate_keypair` starts from: a zero secret and
+    /// the identity as public key.
+    ///
+    /// The secret's limbs are overwritten with zeros in place before it is shrunk to zero. `BigUint` doesn't
+    /// expose its buffer, so this is best effort: copies made earlier, e.g. by `secret_hex` or `clone`, are
+    /// left as they are.
+    pub fn clear(&mut self) {
+        let limbs = self.secret.iter_u32_digits().len();
+        self.secret.assign_from_slice(&vec![0; limbs]);
+        core::hint::black_box(&self.secret);
+        self.public_key = self.curve.identity();
+    }
+
+    /// Imports a secret key from a big-endian hex string and derives its public key.
+    ///
+    /// # Errors
+    /// - `Error::InvalidHex` if `hex` is empty or contains non-hex characters.
+    /// - `Error::SecretOutOfRange` if the secret is not in `[1, order)`.
+    pub fn from_secret_hex(curve: T, hex: &str) -> Result<Self, Error> {
+        if hex.is_empty() || !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Error::InvalidHex);
+        }
+        let secret = BigUint::parse_bytes(hex.as_bytes(), 16).ok_or(Error::InvalidHex)?;
+        if !curve.is_valid_scalar(&secret) {
+            return Err(Error::SecretOutOfRange);
+        }
+
+        let public_key = curve.calculate_public_key(secret.clone());
+        Ok(Self {
+            curve,
+            secret,
+            public_key,
+        })
+    }
+
+    /// Deterministically derives a keypair from a seed, e.g. a passphrase hash.
+    ///
+    /// The secret is SHA-512 of `seed` followed by a 32-bit big-endian counter, reduced modulo the order. The
+    /// digest is at least 128 bits wider than the order of every curve in the crate, so the reduction is
+    /// practically unbiased. The counter starts at zero and only moves on in the (astronomically unlikely) case
+    /// that the result is zero.
+    pub fn from_seed(curve: T, seed: &[u8]) -> Self {
+        let secret = hash_to_secret(&curve, &[seed]);
+
+        let public_key = curve.calculate_public_key(secret.clone());
+        Self {
+            curve,
+            secret,
+            public_key,
+        }
+    }
+
+    /// Exports the secret key as lowercase hex, left-padded to the byte length of the order.
+    pub fn secret_hex(&self) -> String {
+        let width = 2 * self.curve.order_byte_size();
+        format!("{:0width$x}", self.secret)
+    }
+
+    /// Returns the public key in compressed SEC1 form, about half the size of the affine point.
+    pub fn compressed_public_key(&self) -> Vec<u8> {
+        to_sec1(&self.public_key, true, &self.curve)
+    }
+
+    /// Returns the compressed SEC1 public key as lowercase hex, e.g. 66 characters for a 256-bit curve.
+    pub fn compressed_public_key_hex(&self) -> String {
+        self.compressed_public_key()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    /// Expands a (compressed or uncompressed) SEC1 public key of this signer's curve back into a point.
+    ///
+    /// # Errors
+    /// - Any error raised by `from_sec1`.
+    pub fn expand_public_key(&self, bytes: &[u8]) -> Result<CurvePoint, Error> {
+        from_sec1(bytes, &self.curve)
+    }
+
+    /// Exports the keypair as a SEC1 `ECPrivateKey` (RFC 5915), the DER behind OpenSSL's `EC PRIVATE KEY` PEM.
+    ///
+    /// The secret is left-padded to the byte length of the order and the public key is uncompressed, as OpenSSL
+    /// writes them. The curve parameters are only included if the curve has an [`Curve::oid`].
+    pub fn to_sec1_der(&self) -> Vec<u8> {
+        let mut body = der::integer(&BigUint::from(1_u8));
+        body.extend_from_slice(&der::element(
+            der::OCTET_STRING,
+            &scalar_to_be_bytes(&self.secret, &self.curve),
+        ));
+        if let Some(oid) = self.curve.oid() {
+            body.extend_from_slice(&der::element(
+                der::context_tag(0),
+                &der::element(der::OBJECT_IDENTIFIER, oid),
+            ));
+        }
+
+        // The leading byte counts the unused bits at the end of the bit string
+        let mut public_key = vec![0];
+        public_key.extend_from_slice(&to_sec1(&self.public_key, false, &self.curve));
+        body.extend_from_slice(&der::element(
+            der::context_tag(1),
+            &der::element(der::BIT_STRING, &public_key),
+        ));
+
+        der::element(der::SEQUENCE, &body)
+    }
+
+    /// Imports a keypair from a SEC1 `ECPrivateKey`, e.g. one written by [`Signature::to_sec1_der`] or OpenSSL.
+    ///
+    /// The curve parameters and the public key are optional. If present, they must name `curve` and match the
+    /// secret, respectively.
+    ///
+    /// # Errors
+    /// - `Error::InvalidEncoding` if the bytes are not a version 1 `ECPrivateKey`, the secret isn't as long as
+    ///   the order, or the parameters name another curve.
+    /// - `Error::SecretOutOfRange` if the secret is not in `[1, order)`.
+    /// - `Error::KeyMismatch` if the public key doesn't belong to the secret.
+    /// - Any error raised by `from_sec1` for the public key.
+    pub fn from_sec1_der(curve: T, bytes: &[u8]) -> Result<Self, Error> {
+        let (body, rest) = der::read_element(bytes, der::SEQUENCE).ok_or(Error::InvalidEncoding)?;
+        if !rest.is_empty() {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let (version, body) = der::read_integer(body).ok_or(Error::InvalidEncoding)?;
+        if version != BigUint::from(1_u8) {
+            return Err(Error::InvalidEncoding);
+        }
+        let (secret, mut body) =
+            der::read_element(body, der::OCTET_STRING).ok_or(Error::InvalidEncoding)?;
+        if secret.len() != curve.order_byte_size() {
+            return Err(Error::InvalidEncoding);
+        }
+        let secret = BigUint::from_bytes_be(secret);
+
+        if let Some((parameters, rest)) = der::read_element(body, der::context_tag(0)) {
+            match der::read_element(parameters, der::OBJECT_IDENTIFIER) {
+                Some((oid, [])) if curve.oid() == Some(oid) => body = rest,
+                _ => return Err(Error::InvalidEncoding),
+            }
+        }
+
+        let mut claimed_public_key = None;
+        if let Some((public_key, rest)) = der::read_element(body, der::context_tag(1)) {
+            match der::read_element(public_key, der::BIT_STRING) {
+                Some(([0, point @ ..], [])) => {
+                    claimed_public_key = Some(from_sec1(point, &curve)?);
+                    body = rest;
+                }
+                _ => return Err(Error::InvalidEncoding),
+            }
+        }
+        if !body.is_empty() {
+            return Err(Error::InvalidEncoding);
+        }
+
+        if !curve.is_valid_scalar(&secret) {
+            return Err(Error::SecretOutOfRange);
+        }
+        let public_key = curve.calculate_public_key(secret.clone());
+        if claimed_public_key.is_some_and(|claimed| claimed != public_key) {
+            return Err(Error::KeyMismatch);
+        }
+
+        Ok(Self {
+            curve,
+            secret,
+            public_key,
+        })
+    }
+}
+
+/// Checks that `public` is the public key of `secret`, i.e. that \( dG = Q \).
+///
+/// Meant for imported key material, to catch a corrupted or mismatched pair before it is used to sign.
+///
+/// # Returns
+/// - `false` if `secret` is not in `[1, order)` or \( dG \) differs from `public`.
+pub fn check_keypair<T: Curve>(secret: &BigUint, public: &CurvePoint, curve: &T) -> bool {
+    if !curve.is_valid_scalar(secret) {
+        return false;
+    }
+    curve.calculate_public_key(secret.clone()) == curve.normalize(public.clone())
+}
+
+/// Prints the curve and the public key, but never the secret key, so that signers can be logged safely
+impl<T: Curve + core::fmt::Debug> core::fmt::Debug for Signature<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Signature")
+            .field("curve", &self.curve)
+            .field("secret", &format_args!("[REDACTED]"))
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use num_bigint::BigUint;
+
+    use super::{check_keypair, Signature};
+    use crate::{
+        elliptic_curves::{
+            brainpool::BrainpoolP256r1,
+            curve::{Curve, CurvePoint},
+            generic::GenericCurve,
+            p256::P256,
+            p384::P384,
+            secp256k1::Secp256k1,
+        },
+        error::Error,
+    };
+
+    /// Keys generated with `openssl ecparam -name <curve> -genkey -noout -outform DER`
+    const OPENSSL_SECP256K1_KEY: &str = "30740201010420914c4cd1a75886a19b62c1c7077ebc9e847c3038278cd5a1d7963dd6430508d1a00706052b8104000aa14403420004ef501a7c09ad2a6127cbc44dd08f5d0cda7f03ad7ff8c8972f0295d498750663a982cf8b04528f7459851dc3359d5b3348ed406a72773dd449eff7108625e5fa";
+    const OPENSSL_P256_KEY: &str = "30770201010420d1e624f4aa7a440a43f5ee3e556eac243bd264b26cfde8d0555ded59b9b8f534a00a06082a8648ce3d030107a144034200042d2662f17f27f834d2a5e87ad56fe01ade969a647fcf2f9471e3b1bb9acf196befd74c0dd12dd899256b106e11b96fae210733719913b9ce8a52c869417c3487";
+    const OPENSSL_P384_KEY: &str = "3081a40201010430a248b34d7f511d1bdd34a778d28727d494a221ea777c9b2ab99119da15649140cbef5e9c7766151c59312454384286ffa00706052b81040022a16403620004b5f75d04ff0f8c0b53d78ee48cfca02309bceb73d962775e5ecbc08fccce10ffb55b7b93c667e70bda59bb6a3dba4399ff08f752e3fb8f729c890d84186da7c74446fa409768884234f2167a8978d0f58c08fb907df290387f32f3f2443ee51c";
+    const OPENSSL_BRAINPOOL_KEY: &str = "30780201010420a36de30dce739a3a89504a4345e42f5b20813337947724605a30a88840d4b411a00b06092b2403030208010107a1440342000466b3f8fb846926ca24797ce6b035ccceb91f13c81f650872e5184a9ad154bd705f26cad2feba67b21f87887fce68d9de2fd8b850960f56d1231d0bf3e8fddf04";
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn should_round_trip_secret_hex() {
+        let hex = "00000000000000000000000000000000000000000000000000000000000000ff";
+        let signature = Signature::from_secret_hex(Secp256k1, hex).unwrap();
+
+        assert_eq!(signature.secret, BigUint::from(255_u8));
+        assert_eq!(signature.secret_hex(), hex);
+        assert_eq!(
+            signature.public_key,
+            Secp256k1.calculate_public_key(BigUint::from(255_u8))
+        );
+    }
+
+    #[test]
+    fn should_accept_unpadded_uppercase_hex() {
+        let signature = Signature::from_secret_hex(Secp256k1, "ABC").unwrap();
+        assert_eq!(signature.secret, BigUint::from(0xabc_u16));
+    }
+
+    #[test]
+    fn should_reject_out_of_range_secret() {
+        let order = format!("{:x}", Secp256k1.order());
+        let above_order = format!("{:x}", Secp256k1.order() + 1_u8);
+
+        for hex in ["0", "0000", order.as_str(), above_order.as_str()] {
+            assert_eq!(
+                Signature::from_secret_hex(Secp256k1, hex).err(),
+                Some(Error::SecretOutOfRange)
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_non_hex_input() {
+        for hex in ["", "xyz", "0x01", "+1", "1_0", " 1"] {
+            assert_eq!(
+                Signature::from_secret_hex(Secp256k1, hex).err(),
+                Some(Error::InvalidHex)
+            );
+        }
+    }
+
+    #[test]
+    fn should_round_trip_compressed_public_key() {
+        let signature = Signature::from_secret_hex(Secp256k1, "ABC").unwrap();
+        let compressed = signature.compressed_public_key();
+
+        assert_eq!(compressed.len(), 33);
+        assert_eq!(
+            signature.expand_public_key(&compressed),
+            Ok(signature.public_key.clone())
+        );
+        assert_eq!(
+            signature.expand_public_key(&compressed[1..]),
+            Err(Error::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn should_encode_compressed_public_key_as_hex() {
+        let cases = [
+            (
+                "1",
+                "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            ),
+            // The public key of OPENSSL_SECP256K1_KEY, whose y-coordinate is even
+            (
+                "914c4cd1a75886a19b62c1c7077ebc9e847c3038278cd5a1d7963dd6430508d1",
+                "02ef501a7c09ad2a6127cbc44dd08f5d0cda7f03ad7ff8c8972f0295d498750663",
+            ),
+        ];
+
+        for (secret, expected) in cases {
+            let signature = Signature::from_secret_hex(Secp256k1, secret).unwrap();
+            assert_eq!(signature.compressed_public_key_hex(), expected);
+        }
+    }
+
+    #[test]
+    fn should_redact_secret_in_debug_output() {
+        let hex = "b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef";
+        let signature = Signature::from_secret_hex(Secp256k1, hex).unwrap();
+        let debug = format!("{:?}", signature);
+
+        assert!(debug.contains("[REDACTED]"));
+        assert!(debug.contains(&format!("{:?}", signature.public_key)));
+        assert!(!debug.contains(&signature.secret.to_string()));
+        assert!(!debug.to_lowercase().contains(hex));
+        assert!(!format!("{:#?}", signature).contains(&signature.secret.to_string()));
+    }
+
+    #[test]
+    fn should_derive_public_key_when_generating_keypair() {
+        let mut signature = Signature {
+            curve: Secp256k1,
+            secret: BigUint::ZERO,
+            public_key: Secp256k1.identity(),
+        };
+        signature.generate_keypair();
+
+        assert_eq!(
+            signature.public_key,
+            Secp256k1.calculate_public_key(signature.secret.clone())
+        );
+    }
+
+    #[test]
+    fn should_derive_deterministic_keypair_from_seed() {
+        let first = Signature::from_seed(Secp256k1, b"correct horse battery staple");
+        let again = Signature::from_seed(Secp256k1, b"correct horse battery staple");
+        let other = Signature::from_seed(Secp256k1, b"correct horse battery stapler");
+
+        assert_eq!(first.secret, again.secret);
+        assert_eq!(first.public_key, again.public_key);
+        assert_ne!(first.secret, other.secret);
+        assert!(check_keypair(&first.secret, &first.public_key, &Secp256k1));
+
+        // A 64-byte digest reduced into an order much smaller than 2^512
+        let small = GenericCurve::toy();
+        for seed in [&b""[..], b"a", b"b"] {
+            let signature = Signature::from_seed(small.clone(), seed);
+            assert!(small.is_valid_scalar(&signature.secret));
+        }
+    }
+
+    #[test]
+    fn should_wipe_keypair_when_cleared() {
+        let mut signature = Signature::from_secret_hex(Secp256k1, "c0ffee").unwrap();
+        signature.clear();
+
+        assert_eq!(signature.secret, BigUint::ZERO);
+        assert_eq!(signature.public_key, Secp256k1.identity());
+
+        // A cleared signer can be given a new keypair
+        signature.generate_keypair();
+        assert!(check_keypair(
+            &signature.secret,
+            &signature.public_key,
+            &Secp256k1
+        ));
+    }
+
+    #[test]
+    fn should_accept_matching_keypair() {
+        let signature = Signature::from_secret_hex(Secp256k1, "ABC").unwrap();
+        assert!(check_keypair(
+            &signature.secret,
+            &signature.public_key,
+            &Secp256k1
+        ));
+
+        let signature = Signature::from_secret_hex(P256, "ABC").unwrap();
+        assert!(check_keypair(
+            &signature.secret,
+            &signature.public_key,
+            &P256
+        ));
+    }
+
+    #[test]
+    fn should_reject_mismatched_keypair() {
+        let signature = Signature::from_secret_hex(Secp256k1, "ABC").unwrap();
+        let other = Secp256k1.calculate_public_key(BigUint::from(0xabd_u16));
+
+        assert!(!check_keypair(&signature.secret, &other, &Secp256k1));
+        assert!(!check_keypair(
+            &signature.secret,
+            &CurvePoint::Infinity,
+            &Secp256k1
+        ));
+        // The same secret has a different public key on another curve
+        assert!(!check_keypair(
+            &signature.secret,
+            &signature.public_key,
+            &P256
+        ));
+        // Out-of-range secrets have no public key
+        assert!(!check_keypair(
+            &(Secp256k1.order() + &signature.secret),
+            &signature.public_key,
+            &Secp256k1
+        ));
+    }
+
+    #[test]
+    fn should_read_and_reproduce_openssl_keys() {
+        fn check<T: Curve>(curve: T, hex: &str) {
+            let bytes = from_hex(hex);
+            let signature = Signature::from_sec1_der(curve, &bytes).unwrap();
+            assert_eq!(signature.to_sec1_der(), bytes);
+        }
+
+        check(Secp256k1, OPENSSL_SECP256K1_KEY);
+        check(P256, OPENSSL_P256_KEY);
+        check(P384, OPENSSL_P384_KEY);
+        check(BrainpoolP256r1, OPENSSL_BRAINPOOL_KEY);
+
+        let signature =
+            Signature::from_sec1_der(Secp256k1, &from_hex(OPENSSL_SECP256K1_KEY)).unwrap();
+        assert_eq!(
+            signature.secret_hex(),
+            "914c4cd1a75886a19b62c1c7077ebc9e847c3038278cd5a1d7963dd6430508d1"
+        );
+    }
+
+    #[test]
+    fn should_round_trip_sec1_der() {
+        let signature = Signature::from_secret_hex(Secp256k1, "ABC").unwrap();
+        let imported = Signature::from_sec1_der(Secp256k1, &signature.to_sec1_der()).unwrap();
+        assert_eq!(imported.secret, signature.secret);
+        assert_eq!(imported.public_key, signature.public_key);
+
+        // Without an OID the parameters are left out, leaving the version, the 1-byte secret and the bit string
+        // with the 3-byte point
+        let curve = GenericCurve::toy();
+        let signature = Signature::from_secret_hex(curve.clone(), "7").unwrap();
+        let bytes = signature.to_sec1_der();
+        assert_eq!(bytes.len(), 2 + 3 + 3 + 2 + 2 + 4);
+        let imported = Signature::from_sec1_der(curve, &bytes).unwrap();
+        assert_eq!(imported.public_key, signature.public_key);
+    }
+
+    #[test]
+    fn should_accept_sec1_der_without_optional_fields() {
+        let signature = Signature::from_secret_hex(Secp256k1, "ABC").unwrap();
+        // SEQUENCE { INTEGER 1, OCTET STRING secret }
+        let mut bytes = from_hex("30250201010420");
+        bytes.extend_from_slice(&from_hex(&signature.secret_hex()));
+
+        let imported = Signature::from_sec1_der(Secp256k1, &bytes).unwrap();
+        assert_eq!(imported.public_key, signature.public_key);
+    }
+
+    #[test]
+    fn should_reject_malformed_sec1_der() {
+        let bytes = from_hex(OPENSSL_SECP256K1_KEY);
+
+        // Another curve
+        assert_eq!(
+            Signature::from_sec1_der(P256, &bytes).err(),
+            Some(Error::InvalidEncoding)
+        );
+
+        // Version 2
+        let mut version = bytes.clone();
+        version[4] = 2;
+        // Truncated, and with a trailing byte
+        let truncated = bytes[..bytes.len() - 1].to_vec();
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        for bytes in [version, truncated, trailing, Vec::new()] {
+            assert_eq!(
+                Signature::from_sec1_der(Secp256k1, &bytes).err(),
+                Some(Error::InvalidEncoding)
+            );
+        }
+
+        // The public key belongs to another secret
+        let mut mismatched = bytes.clone();
+        mismatched[7] ^= 1;
+        assert_eq!(
+            Signature::from_sec1_der(Secp256k1, &mismatched).err(),
+            Some(Error::KeyMismatch)
+        );
+
+        // A zero secret
+        let mut zero = bytes.clone();
+        zero[7..39].fill(0);
+        assert_eq!(
+            Signature::from_sec1_der(Secp256k1, &zero).err(),
+            Some(Error::SecretOutOfRange)
+        );
+    }
 }
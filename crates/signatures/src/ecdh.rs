@@ -0,0 +1,161 @@
+use alloc::{vec, vec::Vec};
+
+use hkdf::Hkdf;
+use num_bigint::BigUint;
+use sha2::Sha256;
+
+use crate::{
+    digital_signature::Signature,
+    elliptic_curves::curve::{Curve, CurvePoint},
+    error::Error,
+    public_key::PublicKey,
+};
+
+/// Computes the elliptic curve Diffie-Hellman shared secret (SEC 1, section 3.3.1).
+///
+/// The shared point is \( dQ \) for the local secret \( d \) and the peer's public key \( Q \). The secret is
+/// its x-coordinate, big-endian and left-padded to the curve's `field_byte_size`.
+///
+/// The curve is the one of `public_key`, which `PublicKey` already guarantees to be on it and not the point
+/// at infinity.
+///
+/// # Errors
+/// - `Error::SecretOutOfRange` if `secret` is not in `[1, order)`.
+/// - `Error::InvalidPublicKey` if the shared point is the point at infinity.
+pub fn ecdh<T: Curve>(secret: &BigUint, public_key: &PublicKey<T>) -> Result<Vec<u8>, Error> {
+    let curve = public_key.curve();
+    if !curve.is_valid_scalar(secret) {
+        return Err(Error::SecretOutOfRange);
+    }
+
+    // Only reachable for keys outside the prime-order subgroup (a non-trivial cofactor)
+    let CurvePoint::Affine { x, .. } = curve.scalar_mul(secret, public_key.point().clone()) else {
+        return Err(Error::InvalidPublicKey);
+    };
+
+    let bytes = x.to_bytes_be();
+    let mut shared = Vec::with_capacity(curve.field_byte_size());
+    shared.resize(curve.field_byte_size() - bytes.len(), 0);
+    shared.extend_from_slice(&bytes);
+    Ok(shared)
+}
+
+/// Derives `out_len` bytes of key material from the ECDH shared secret with HKDF-SHA256 (RFC 5869).
+///
+/// The x-coordinate returned by [`ecdh`] is far from uniformly random (it is constrained by the curve equation),
+/// so it shouldn't be used as a symmetric key directly. HKDF runs it through an extract step, without a salt,
+/// and an expand step keyed by `info`. The `info` string should name the purpose of the key, e.g. a protocol
+/// label and the parties' public keys; different `info` values yield independent keys.
+///
+/// # Errors
+/// - Any error raised by [`ecdh`].
+/// - `Error::InvalidKeyLength` if `out_len` is above 8160, the 255 SHA-256 blocks that HKDF-SHA256 can produce
+///   at most.
+pub fn ecdh_kdf<T: Curve>(
+    secret: &BigUint,
+    public_key: &PublicKey<T>,
+    info: &[u8],
+    out_len: usize,
+) -> Result<Vec<u8>, Error> {
+    let shared = ecdh(secret, public_key)?;
+
+    let mut key = vec![0; out_len];
+    Hkdf::<Sha256>::new(None, &shared)
+        .expand(info, &mut key)
+        .map_err(|_| Error::InvalidKeyLength)?;
+    Ok(key)
+}
+
+impl<T: Curve> Signature<T> {
+    /// Derives a symmetric key shared with the owner of `their_public_key`, see [`ecdh_kdf`]
+    pub fn ecdh_kdf(
+        &self,
+        their_public_key: &PublicKey<T>,
+        info: &[u8],
+        out_len: usize,
+    ) -> Result<Vec<u8>, Error> {
+        ecdh_kdf(&self.secret, their_public_key, info, out_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::ecdh;
+    use crate::{
+        digital_signature::Signature,
+        elliptic_curves::{curve::Curve, p256::P256, secp256k1::Secp256k1},
+        error::Error,
+        public_key::PublicKey,
+    };
+
+    #[test]
+    fn should_agree_on_shared_secret() {
+        fn check<T: Curve + Clone>(curve: T) {
+            let alice = BigUint::from(0xa11ce_u32);
+            let bob = BigUint::from(0xb0b_u32);
+            let alice_public =
+                PublicKey::new(curve.clone(), curve.calculate_public_key(alice.clone())).unwrap();
+            let bob_public =
+                PublicKey::new(curve.clone(), curve.calculate_public_key(bob.clone())).unwrap();
+
+            let shared = ecdh(&alice, &bob_public).unwrap();
+            assert_eq!(shared.len(), 32);
+            assert_eq!(ecdh(&bob, &alice_public), Ok(shared));
+        }
+
+        check(Secp256k1);
+        check(P256);
+    }
+
+    #[test]
+    fn should_derive_same_key_for_both_parties() {
+        let alice = Signature::from_seed(Secp256k1, b"alice");
+        let bob = Signature::from_seed(Secp256k1, b"bob");
+        let (alice_public, bob_public) =
+            (alice.to_public_key().unwrap(), bob.to_public_key().unwrap());
+
+        let key = alice.ecdh_kdf(&bob_public, b"shai/test", 32).unwrap();
+        assert_eq!(key.len(), 32);
+        assert_eq!(
+            bob.ecdh_kdf(&alice_public, b"shai/test", 32),
+            Ok(key.clone())
+        );
+        assert_ne!(key, ecdh(&alice.secret, &bob_public).unwrap());
+
+        // Another purpose yields an unrelated key, and a longer key extends the shorter one
+        let other = alice.ecdh_kdf(&bob_public, b"shai/other", 32).unwrap();
+        assert_ne!(other, key);
+        let long = alice.ecdh_kdf(&bob_public, b"shai/test", 80).unwrap();
+        assert_eq!(long.len(), 80);
+        assert_eq!(long[..32], key);
+    }
+
+    #[test]
+    fn should_reject_oversized_key() {
+        let alice = Signature::from_seed(Secp256k1, b"alice");
+        let bob_public = Signature::from_seed(Secp256k1, b"bob")
+            .to_public_key()
+            .unwrap();
+
+        assert_eq!(
+            alice
+                .ecdh_kdf(&bob_public, b"shai/test", 8160)
+                .map(|key| key.len()),
+            Ok(8160)
+        );
+        assert_eq!(
+            alice.ecdh_kdf(&bob_public, b"shai/test", 8161),
+            Err(Error::InvalidKeyLength)
+        );
+    }
+
+    #[test]
+    fn should_reject_out_of_range_secret() {
+        let public_key = PublicKey::new(Secp256k1, Secp256k1.generator_point()).unwrap();
+        for secret in [BigUint::ZERO, Secp256k1.order()] {
+            assert_eq!(ecdh(&secret, &public_key), Err(Error::SecretOutOfRange));
+        }
+    }
+}
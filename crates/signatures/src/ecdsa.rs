@@ -0,0 +1,1460 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use num_bigint::{BigUint, RandBigInt};
+#[cfg(feature = "std")]
+use rand::thread_rng;
+use rand::RngCore;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use sha2::Digest;
+
+use crate::{
+    der,
+    digital_signature::Signature,
+    elliptic_curves::{
+        arithmetic::{add_two_points, multi_scalar_mul},
+        constant_time::{ct_eq, ct_mod_inv},
+        curve::{Curve, CurvePoint},
+        scalar::{add_mod_order, inv_mod_order, mul_mod_order},
+        sec1::to_padded_bytes,
+    },
+    error::Error,
+    public_key::PublicKey,
+};
+
+/// An ECDSA signature \( (r, s) \).
+///
+/// [`EcdsaSignature::new`] doesn't check the components, so that e.g. a leniently decoded signature can be
+/// represented; [`verify_prehashed`] rejects any component outside \( [1, n) \).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EcdsaSignature {
+    r: BigUint,
+    s: BigUint,
+}
+
+impl EcdsaSignature {
+    /// Creates a signature from its components without checking them
+    pub fn new(r: BigUint, s: BigUint) -> Self {
+        Self { r, s }
+    }
+
+    /// Returns the x-coordinate of \( kG \) reduced modulo \( n \)
+    pub fn r(&self) -> &BigUint {
+        &self.r
+    }
+
+    /// Returns \( k^{-1}(z + rd) \mod n \)
+    pub fn s(&self) -> &BigUint {
+        &self.s
+    }
+
+    /// Consumes the signature and returns the pair `(r, s)`
+    pub fn into_parts(self) -> (BigUint, BigUint) {
+        (self.r, self.s)
+    }
+
+    /// Verifies the signature over an already hashed message, see [`verify_prehashed`]
+    pub fn verify<T: Curve>(&self, message_hash: &BigUint, public_key: &PublicKey<T>) -> bool {
+        verify_prehashed(message_hash, self, public_key)
+    }
+
+    /// Like [`EcdsaSignature::verify`], but reports why the signature was rejected, see
+    /// [`verify_prehashed_detailed`]
+    pub fn verify_detailed<T: Curve>(
+        &self,
+        message_hash: &BigUint,
+        public_key: &PublicKey<T>,
+    ) -> Result<(), VerifyError> {
+        verify_prehashed_detailed(message_hash, self, public_key)
+    }
+
+    /// Encodes the signature in the fixed-width compact form `r || s` used by e.g. libsodium and WebCrypto.
+    ///
+    /// Both components are scalars modulo the order, so they are big-endian and left-padded to the curve's
+    /// `order_byte_size`, like every other scalar encoding of the crate.
+    pub fn to_compact<T: Curve>(&self, curve: &T) -> Vec<u8> {
+        let size = curve.order_byte_size();
+        let mut bytes = Vec::with_capacity(2 * size);
+        bytes.extend_from_slice(&to_padded_bytes(&self.r, size));
+        bytes.extend_from_slice(&to_padded_bytes(&self.s, size));
+        bytes
+    }
+
+    /// Decodes a compact `r || s` signature, see [`EcdsaSignature::to_compact`].
+    ///
+    /// This is the strict decoder: only canonical components, in `[1, n)`, are accepted, as some consensus
+    /// systems require. See [`EcdsaSignature::from_compact_lenient`] for the alternative.
+    ///
+    /// # Errors
+    /// - `Error::InvalidSignature` if the length isn't exactly `2 * order_byte_size` or a component is not in
+    ///   `[1, n)`.
+    pub fn from_compact<T: Curve>(bytes: &[u8], curve: &T) -> Result<Self, Error> {
+        Self::from_compact_lenient(bytes, curve)?.check_components(curve)
+    }
+
+    /// Decodes a compact `r || s` signature without checking that the components are in `[1, n)`.
+    ///
+    /// Such signatures never pass [`verify_prehashed`], so this only defers the rejection to verification.
+    ///
+    /// # Errors
+    /// - `Error::InvalidSignature` if the length isn't exactly `2 * order_byte_size`.
+    pub fn from_compact_lenient<T: Curve>(bytes: &[u8], curve: &T) -> Result<Self, Error> {
+        let size = curve.order_byte_size();
+        if bytes.len() != 2 * size {
+            return Err(Error::InvalidSignature);
+        }
+
+        let (r, s) = bytes.split_at(size);
+        Ok(Self::new(
+            BigUint::from_bytes_be(r),
+            BigUint::from_bytes_be(s),
+        ))
+    }
+
+    /// Encodes the signature as the DER structure `SEQUENCE { INTEGER r, INTEGER s }` used by X.509, TLS and
+    /// Bitcoin.
+    ///
+    /// Each integer is big-endian in as few bytes as possible, with a leading zero byte when its top bit is set
+    /// (DER integers are signed).
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut body = der::integer(&self.r);
+        body.extend_from_slice(&der::integer(&self.s));
+        der::element(der::SEQUENCE, &body)
+    }
+
+    /// Decodes a DER signature, see [`EcdsaSignature::to_der`].
+    ///
+    /// Like [`EcdsaSignature::from_compact`] this is the strict decoder, which also requires both components to
+    /// be in `[1, n)`.
+    ///
+    /// # Errors
+    /// - `Error::InvalidSignature` if the bytes are not a DER encoded signature or a component is not in
+    ///   `[1, n)`.
+    pub fn from_der<T: Curve>(bytes: &[u8], curve: &T) -> Result<Self, Error> {
+        Self::from_der_lenient(bytes)?.check_components(curve)
+    }
+
+    /// Decodes a DER signature without checking that the components are in `[1, n)`.
+    ///
+    /// The encoding itself is still checked strictly: non-minimal lengths and integers, negative integers and
+    /// trailing bytes are rejected.
+    ///
+    /// # Errors
+    /// - `Error::InvalidSignature` if the bytes are not a DER encoded signature.
+    pub fn from_der_lenient(bytes: &[u8]) -> Result<Self, Error> {
+        let (body, rest) =
+            der::read_element(bytes, der::SEQUENCE).ok_or(Error::InvalidSignature)?;
+        if !rest.is_empty() {
+            return Err(Error::InvalidSignature);
+        }
+
+        let (r, body) = der::read_integer(body).ok_or(Error::InvalidSignature)?;
+        let (s, body) = der::read_integer(body).ok_or(Error::InvalidSignature)?;
+        if !body.is_empty() {
+            return Err(Error::InvalidSignature);
+        }
+        Ok(Self::new(r, s))
+    }
+
+    fn check_components<T: Curve>(self, curve: &T) -> Result<Self, Error> {
+        if !is_well_formed_signature(&self.r, &self.s, curve) {
+            return Err(Error::InvalidSignature);
+        }
+        Ok(self)
+    }
+}
+
+impl<T: Curve> Signature<T> {
+    /// Signs an already hashed message with ECDSA using a random nonce.
+    #[cfg(feature = "std")]
+    pub fn sign(&self, message_hash: &BigUint) -> EcdsaSignature {
+        self.sign_with_rng(message_hash, &mut thread_rng())
+    }
+
+    /// Signs an already hashed message with ECDSA, drawing the nonce from the given generator.
+    ///
+    /// Picks a random nonce \( k \in [1, n) \) and signs with [`Signature::sign_with_nonce`], retrying with a
+    /// new nonce in the (negligibly likely) case that \( r \) or \( s \) is zero.
+    pub fn sign_with_rng<R: RngCore + ?Sized>(
+        &self,
+        message_hash: &BigUint,
+        rng: &mut R,
+    ) -> EcdsaSignature {
+        let n = self.curve.order();
+
+        loop {
+            let k = rng.gen_biguint_range(&BigUint::from(1_u8), &n);
+            if let Ok(signature) = self.sign_with_nonce(message_hash, k) {
+                return signature;
+            }
+        }
+    }
+
+    /// Signs an already hashed message with ECDSA using the caller-supplied nonce `k`.
+    ///
+    /// **For tests and advanced use only.** This exists to reproduce published test vectors. Reusing a nonce
+    /// for two different messages, or using a predictable one, reveals the secret key.
+    ///
+    /// 1. \( R = kG \) and \( r = R_x \mod n \).
+    /// 2. \( s = k^{-1}(z + rd) \mod n \).
+    ///
+    /// # Errors
+    /// - `Error::ScalarOutOfRange` if `k` is not below the order.
+    /// - `Error::InvalidSignature` if the nonce yields \( r = 0 \) or \( s = 0 \).
+    pub fn sign_with_nonce(
+        &self,
+        message_hash: &BigUint,
+        k: BigUint,
+    ) -> Result<EcdsaSignature, Error> {
+        let n = self.curve.order();
+        if k >= n {
+            return Err(Error::ScalarOutOfRange);
+        }
+        let z = self.curve.reduce_scalar(message_hash);
+
+        let CurvePoint::Affine { x, .. } = self.curve.calculate_public_key(k.clone()) else {
+            return Err(Error::InvalidSignature);
+        };
+        let r = x % &n;
+        if ct_eq(&r, &BigUint::ZERO) {
+            return Err(Error::InvalidSignature);
+        }
+        // k is secret, and in [1, n) since kG isn't the point at infinity
+        let s = mul_mod_order(
+            &ct_mod_inv(&k, &n),
+            &add_mod_order(
+                &z,
+                &mul_mod_order(&r, &self.secret, &self.curve),
+                &self.curve,
+            ),
+            &self.curve,
+        );
+        if ct_eq(&s, &BigUint::ZERO) {
+            return Err(Error::InvalidSignature);
+        }
+
+        Ok(EcdsaSignature::new(r, s))
+    }
+
+    /// Reduces a big-endian message digest as described in [`digest_to_scalar`] and signs it.
+    ///
+    /// The digest may come from any hash function, and may be longer than the order.
+    #[cfg(feature = "std")]
+    pub fn sign_digest(&self, digest: &[u8]) -> EcdsaSignature {
+        self.sign(&digest_to_scalar(digest, &self.curve))
+    }
+
+    /// Hashes `message` with the digest `H`, reduces it as described in [`hash_message`] and signs it.
+    #[cfg(feature = "std")]
+    pub fn sign_message<H: Digest>(&self, message: &[u8]) -> EcdsaSignature {
+        self.sign_digest(&H::digest(message))
+    }
+}
+
+/// An opt-in guard against ECDSA nonce reuse for the signatures of one session.
+///
+/// Two signatures \( (r, s_1) \) and \( (r, s_2) \) over different messages with the same nonce reveal it as
+/// \( k = (z_1 - z_2)(s_1 - s_2)^{-1} \), and the secret key with it. Since \( r \) is determined by \( k \), the
+/// context records every `r` it hands out along with the message it signed, and refuses to sign a different
+/// message once an `r` comes up again. Re-signing the same message is allowed.
+pub struct SigningContext<'a, T: Curve> {
+    signer: &'a Signature<T>,
+    used: BTreeMap<BigUint, BigUint>,
+}
+
+impl<'a, T: Curve> SigningContext<'a, T> {
+    /// Starts a session with no recorded nonces
+    pub fn new(signer: &'a Signature<T>) -> Self {
+        Self {
+            signer,
+            used: BTreeMap::new(),
+        }
+    }
+
+    /// Signs like [`Signature::sign`], failing if the random nonce was already used for another message
+    ///
+    /// # Errors
+    /// - `Error::NonceReuse` if the nonce was already used for a different message.
+    #[cfg(feature = "std")]
+    pub fn sign(&mut self, message_hash: &BigUint) -> Result<EcdsaSignature, Error> {
+        self.sign_with_rng(message_hash, &mut thread_rng())
+    }
+
+    /// Signs like [`Signature::sign_with_rng`], failing if the nonce was already used for another message
+    ///
+    /// # Errors
+    /// - `Error::NonceReuse` if the nonce was already used for a different message.
+    pub fn sign_with_rng<R: RngCore + ?Sized>(
+        &mut self,
+        message_hash: &BigUint,
+        rng: &mut R,
+    ) -> Result<EcdsaSignature, Error> {
+        let signature = self.signer.sign_with_rng(message_hash, rng);
+        self.record(message_hash, signature)
+    }
+
+    /// Signs like [`Signature::sign_with_nonce`], failing if `k` was already used for another message
+    ///
+    /// # Errors
+    /// - `Error::NonceReuse` if `k` was already used for a different message.
+    /// - Any error raised by `sign_with_nonce`.
+    pub fn sign_with_nonce(
+        &mut self,
+        message_hash: &BigUint,
+        k: BigUint,
+    ) -> Result<EcdsaSignature, Error> {
+        let signature = self.signer.sign_with_nonce(message_hash, k)?;
+        self.record(message_hash, signature)
+    }
+
+    /// Records the `r` of a fresh signature, or rejects it if `r` was seen with a different message
+    fn record(
+        &mut self,
+        message_hash: &BigUint,
+        signature: EcdsaSignature,
+    ) -> Result<EcdsaSignature, Error> {
+        let z = self.signer.curve.reduce_scalar(message_hash);
+        match self.used.get(signature.r()) {
+            Some(previous) if *previous != z => Err(Error::NonceReuse),
+            Some(_) => Ok(signature),
+            None => {
+                self.used.insert(signature.r().clone(), z);
+                Ok(signature)
+            }
+        }
+    }
+}
+
+/// Signs a message that is fed in chunks, hashing it with `H` as it arrives instead of buffering it.
+///
+/// Signing the chunks `a` and `b` is the same as [`Signature::sign_message`] over `a || b`, and the result
+/// verifies with [`verify_message`] over the whole message.
+///
+/// # Examples
+/// ```rust
+/// use sha2::Sha256;
+/// use signatures::digital_signature::Signature;
+/// use signatures::ecdsa::{verify_message, Signer};
+/// use signatures::elliptic_curves::secp256k1::Secp256k1;
+///
+/// let keypair = Signature::from_secret_hex(Secp256k1, "c0ffee").unwrap();
+/// let mut signer = Signer::<Sha256, _>::new(&keypair);
+/// signer.update(b"hello ");
+/// signer.update(b"world");
+/// let signature = signer.finalize_and_sign();
+///
+/// assert!(verify_message::<Sha256, _>(b"hello world", &signature, &keypair.to_public_key().unwrap()));
+/// ```
+pub struct Signer<'a, H: Digest, T: Curve> {
+    keypair: &'a Signature<T>,
+    hasher: H,
+}
+
+impl<'a, H: Digest, T: Curve> Signer<'a, H, T> {
+    /// Starts hashing an empty message to be signed with `keypair`
+    pub fn new(keypair: &'a Signature<T>) -> Self {
+        Self {
+            keypair,
+            hasher: H::new(),
+        }
+    }
+
+    /// Appends `data` to the message
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    /// Signs the digest of everything passed to `update` so far, like [`Signature::sign_digest`]
+    #[cfg(feature = "std")]
+    pub fn finalize_and_sign(self) -> EcdsaSignature {
+        self.keypair.sign_digest(&self.hasher.finalize())
+    }
+
+    /// Like [`Signer::finalize_and_sign`], drawing the nonce from the given generator
+    pub fn finalize_and_sign_with_rng<R: RngCore + ?Sized>(self, rng: &mut R) -> EcdsaSignature {
+        let digest = self.hasher.finalize();
+        self.keypair
+            .sign_with_rng(&digest_to_scalar(&digest, &self.keypair.curve), rng)
+    }
+}
+
+/// Hashes a message with `H` and converts the digest to a scalar with [`digest_to_scalar`].
+pub fn hash_message<H: Digest, T: Curve>(message: &[u8], curve: &T) -> BigUint {
+    digest_to_scalar(&H::digest(message), curve)
+}
+
+/// Converts a big-endian message digest to a scalar as ECDSA requires (FIPS 186-4, section 6.4).
+///
+/// If the digest is longer than the bit length of the order \( n \), only its leftmost
+/// `order_bits` bits are kept. The result is then reduced modulo \( n \).
+pub fn digest_to_scalar<T: Curve>(digest: &[u8], curve: &T) -> BigUint {
+    let digest_bits = 8 * digest.len() as u64;
+    let order_bits = curve.order_bits();
+    let mut e = BigUint::from_bytes_be(digest);
+    if digest_bits > order_bits {
+        e >>= digest_bits - order_bits;
+    }
+
+    curve.reduce_scalar(&e)
+}
+
+/// Verifies an ECDSA signature over a message, hashing it with `H` and reducing it as described in
+/// [`hash_message`].
+///
+/// This is the counterpart of [`Signature::sign_message`]. Pass the message itself, not its digest.
+pub fn verify_message<H: Digest, T: Curve>(
+    message: &[u8],
+    signature: &EcdsaSignature,
+    public_key: &PublicKey<T>,
+) -> bool {
+    verify_prehashed(
+        &hash_message::<H, T>(message, public_key.curve()),
+        signature,
+        public_key,
+    )
+}
+
+/// The reason an ECDSA signature was rejected by [`verify_prehashed_detailed`].
+///
+/// There is no variant for a public key off the curve or at infinity: a [`PublicKey`] can't be either.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyError {
+    /// `r` is not in `[1, n)`
+    ROutOfRange,
+    /// `s` is not in `[1, n)`
+    SOutOfRange,
+    /// \( u_1 G + u_2 Q \) is the point at infinity, which has no x-coordinate to compare with `r`
+    InfinityResult,
+    /// The x-coordinate of \( u_1 G + u_2 Q \) reduced modulo \( n \) differs from `r`
+    RMismatch,
+    /// `s` has no inverse modulo \( n \), which only happens if the order of the curve is not prime
+    SNotInvertible,
+}
+
+impl core::fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VerifyError::ROutOfRange => write!(f, "r is not in [1, order)"),
+            VerifyError::SOutOfRange => write!(f, "s is not in [1, order)"),
+            VerifyError::InfinityResult => write!(f, "u1 G + u2 Q is the point at infinity"),
+            VerifyError::RMismatch => write!(f, "r does not match the recomputed point"),
+            VerifyError::SNotInvertible => write!(f, "s is not invertible modulo the order"),
+        }
+    }
+}
+
+impl core::error::Error for VerifyError {}
+
+/// Checks that both components of a signature are in \( [1, n) \), without any curve arithmetic.
+///
+/// Signatures failing this check never verify, so servers can reject them before paying for
+/// [`verify_prehashed`]. Passing it says nothing about whether the signature is valid.
+pub fn is_well_formed_signature<T: Curve>(r: &BigUint, s: &BigUint, curve: &T) -> bool {
+    curve.is_valid_scalar(r) && curve.is_valid_scalar(s)
+}
+
+/// Verifies an ECDSA signature over an already hashed message, e.g. the output of [`hash_message`].
+///
+/// This is [`verify_prehashed_detailed`] without the reason for a rejection.
+pub fn verify_prehashed<T: Curve>(
+    message_hash: &BigUint,
+    signature: &EcdsaSignature,
+    public_key: &PublicKey<T>,
+) -> bool {
+    verify_prehashed_detailed(message_hash, signature, public_key).is_ok()
+}
+
+/// Verifies an ECDSA signature against a serialized public key, as received from a peer or a certificate.
+///
+/// The key is decoded with [`PublicKey::from_sec1`], so both the compressed and the uncompressed SEC1 form are
+/// accepted, and then checked with [`verify_prehashed`].
+///
+/// # Errors
+/// - Any error raised by `PublicKey::from_sec1`, i.e. the bytes are not a valid public key of `curve`.
+///
+/// # Returns
+/// - Whether the signature is valid for the decoded key.
+pub fn verify_with_pubkey_bytes<T: Curve>(
+    message_hash: &BigUint,
+    signature: &EcdsaSignature,
+    public_key: &[u8],
+    curve: T,
+) -> Result<bool, Error> {
+    let public_key = PublicKey::from_sec1(curve, public_key)?;
+    Ok(verify_prehashed(message_hash, signature, &public_key))
+}
+
+/// Verifies an ECDSA signature over an already hashed message, reporting why it was rejected.
+///
+/// The curve is the one of `public_key`, which is guaranteed to be on it and not the point at infinity: the
+/// identity in particular would make \( u_2 Q \) vanish from the check.
+///
+/// The signature is accepted iff \( r, s \in [1, n) \) and the x-coordinate of
+/// \( u_1 G + u_2 Q \) reduced modulo \( n \) equals \( r \), where \( w = s^{-1} \),
+/// \( u_1 = zw \) and \( u_2 = rw \).
+///
+/// # Errors
+/// - `VerifyError::ROutOfRange` or `VerifyError::SOutOfRange` if a component is not in `[1, n)`.
+/// - `VerifyError::InfinityResult` if \( u_1 G + u_2 Q = O \).
+/// - `VerifyError::RMismatch` if the x-coordinate doesn't match `r`, e.g. for a different message or key.
+/// - `VerifyError::SNotInvertible` if `s` shares a factor with a composite order, which `GenericCurve` doesn't
+///   rule out.
+pub fn verify_prehashed_detailed<T: Curve>(
+    message_hash: &BigUint,
+    signature: &EcdsaSignature,
+    public_key: &PublicKey<T>,
+) -> Result<(), VerifyError> {
+    let curve = public_key.curve();
+    check_signature(message_hash, signature, curve, |u1, u2| {
+        multi_scalar_mul(
+            &[
+                (u1, curve.generator_point()),
+                (u2, public_key.point().clone()),
+            ],
+            curve,
+        )
+    })
+}
+
+/// The checks of [`verify_prehashed_detailed`], with \( u_1 G + u_2 Q \) computed by `combine(u1, u2)`
+fn check_signature<T: Curve>(
+    message_hash: &BigUint,
+    signature: &EcdsaSignature,
+    curve: &T,
+    combine: impl FnOnce(BigUint, BigUint) -> CurvePoint,
+) -> Result<(), VerifyError> {
+    let (r, s) = (signature.r(), signature.s());
+    let n = curve.order();
+    if !curve.is_valid_scalar(r) {
+        return Err(VerifyError::ROutOfRange);
+    }
+    if !curve.is_valid_scalar(s) {
+        return Err(VerifyError::SOutOfRange);
+    }
+
+    let z = curve.reduce_scalar(message_hash);
+    // Fermat inversion assumes a prime order. For a composite one it returns a wrong w whenever s shares a
+    // factor with n, so the result is checked rather than trusted.
+    let w = inv_mod_order(s, curve)
+        .ok()
+        .filter(|w| mul_mod_order(s, w, curve) == BigUint::from(1_u8))
+        .ok_or(VerifyError::SNotInvertible)?;
+    let u1 = mul_mod_order(&z, &w, curve);
+    let u2 = mul_mod_order(r, &w, curve);
+
+    match combine(u1, u2) {
+        CurvePoint::Affine { x, .. } if &x % &n == *r => Ok(()),
+        CurvePoint::Affine { .. } => Err(VerifyError::RMismatch),
+        CurvePoint::Infinity => Err(VerifyError::InfinityResult),
+    }
+}
+
+/// `Sync` with the `rayon` feature, which shares the curve between threads in [`verify_batch`], and no bound at
+/// all otherwise.
+#[cfg(feature = "rayon")]
+pub trait MaybeSync: Sync {}
+
+#[cfg(feature = "rayon")]
+impl<T: Sync + ?Sized> MaybeSync for T {}
+
+/// `Sync` with the `rayon` feature, which shares the curve between threads in [`verify_batch`], and no bound at
+/// all otherwise.
+#[cfg(not(feature = "rayon"))]
+pub trait MaybeSync {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T: ?Sized> MaybeSync for T {}
+
+/// Verifies a batch of `(message_hash, signature, public_key)` entries with [`verify_prehashed`].
+///
+/// Entries that share a public key \( Q \), common when one key signs many transactions, share a single
+/// table of \( aG + bQ \), built once per distinct key. Since ECDSA only reveals the x-coordinate of
+/// \( R \), the equations of different signatures can't be summed into one check, so each entry is still
+/// verified on its own, only faster.
+///
+/// With the `rayon` feature the entries are verified in parallel, otherwise one after the other. The results
+/// are the same either way.
+///
+/// # Returns
+/// - One verification result per entry, in the order of the batch.
+pub fn verify_batch<T: Curve + MaybeSync>(
+    batch: &[(BigUint, EcdsaSignature, PublicKey<T>)],
+) -> Vec<bool> {
+    // Keys are only coalesced on the same curve: two `GenericCurve`s share a type but not their parameters
+    let mut groups = BTreeMap::new();
+    let mut tables = Vec::new();
+    let table_of: Vec<usize> = batch
+        .iter()
+        .map(|(_, _, public_key)| {
+            let curve = public_key.curve();
+            let id = (
+                public_key.point(),
+                [curve.prime_modulus(), curve.a(), curve.b(), curve.order()],
+                curve.generator_point(),
+            );
+            *groups.entry(id).or_insert_with(|| {
+                tables.push(JointTable::new(public_key));
+                tables.len() - 1
+            })
+        })
+        .collect();
+
+    #[cfg(feature = "rayon")]
+    let entries = batch.par_iter().zip(table_of.par_iter());
+    #[cfg(not(feature = "rayon"))]
+    let entries = batch.iter().zip(table_of.iter());
+
+    entries
+        .map(|((message_hash, signature, public_key), &table)| {
+            check_signature(message_hash, signature, public_key.curve(), |u1, u2| {
+                tables[table].mul(&u1, &u2)
+            })
+            .is_ok()
+        })
+        .collect()
+}
+
+/// The points \( aG + bQ \) for \( a, b \in [0, 4) \) and a public key \( Q \).
+///
+/// With the table, \( u_1 G + u_2 Q \) scans both scalars two bits at a time and costs one addition per
+/// window, against up to two per bit for [`multi_scalar_mul`]. Building it takes 15 additions, which one
+/// verification already pays back.
+struct JointTable<'a, T: Curve> {
+    curve: &'a T,
+    // Entry 4a + b holds aG + bQ
+    points: Vec<CurvePoint>,
+}
+
+impl<'a, T: Curve> JointTable<'a, T> {
+    fn new(public_key: &'a PublicKey<T>) -> Self {
+        let curve = public_key.curve();
+        let (g, q) = (curve.generator_point(), public_key.point());
+
+        let mut points: Vec<CurvePoint> = Vec::with_capacity(16);
+        for a in 0..4 {
+            for b in 0..4 {
+                let point = match (a, b) {
+                    (0, 0) => CurvePoint::Infinity,
+                    (_, 0) => add_two_points(points[4 * (a - 1)].clone(), g.clone(), curve),
+                    _ => add_two_points(points[4 * a + b - 1].clone(), q.clone(), curve),
+                };
+                points.push(point);
+            }
+        }
+
+        Self { curve, points }
+    }
+
+    /// Computes \( u_1 G + u_2 Q \)
+    fn mul(&self, u1: &BigUint, u2: &BigUint) -> CurvePoint {
+        let window = |scalar: &BigUint, i: u64| {
+            usize::from(scalar.bit(2 * i + 1)) << 1 | usize::from(scalar.bit(2 * i))
+        };
+
+        let mut result = CurvePoint::Infinity;
+        for i in (0..u1.bits().max(u2.bits()).div_ceil(2)).rev() {
+            result = self.curve.double(self.curve.double(result));
+            let index = 4 * window(u1, i) + window(u2, i);
+            if index != 0 {
+                result = add_two_points(result, self.points[index].clone(), self.curve);
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use rand::{rngs::StdRng, SeedableRng};
+    use sha2::{Digest, Sha256, Sha512};
+
+    use super::{
+        digest_to_scalar, hash_message, is_well_formed_signature, verify_message, verify_prehashed,
+        verify_with_pubkey_bytes, EcdsaSignature, Signer, SigningContext, VerifyError,
+    };
+    use crate::{
+        digital_signature::Signature,
+        elliptic_curves::{
+            curve::{Curve, CurvePoint},
+            generic::GenericCurve,
+            p256::P256,
+            secp256k1::Secp256k1,
+        },
+        error::Error,
+        public_key::PublicKey,
+    };
+
+    /// FIPS 186-4 ECDSA vectors for P-256 with SHA-256 from `SigGen.txt` in NIST CAVP's
+    /// `186-4ecdsatestvectors.zip`, as `[d, Qx, Qy, k, SHA-256(Msg), r, s]`
+    const CAVP_P256_SHA256: [[&str; 7]; 5] = [
+        [
+            "519b423d715f8b581f4fa8ee59f4771a5b44c8130b4e3eacca54a56dda72b464",
+            "1ccbe91c075fc7f4f033bfa248db8fccd3565de94bbfb12f3c59ff46c271bf83",
+            "ce4014c68811f9a21a1fdb2c0e6113e06db7ca93b7404e78dc7ccd5ca89a4ca9",
+            "94a1bbb14b906a61a280f245f9e93c7f3b4a6247824f5d33b9670787642a68de",
+            "44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56",
+            "f3ac8061b514795b8843e3d6629527ed2afd6b1f6a555a7acabb5e6f79c8c2ac",
+            "8bf77819ca05a6b2786c76262bf7371cef97b218e96f175a3ccdda2acc058903",
+        ],
+        [
+            "0f56db78ca460b055c500064824bed999a25aaf48ebb519ac201537b85479813",
+            "e266ddfdc12668db30d4ca3e8f7749432c416044f2d2b8c10bf3d4012aeffa8a",
+            "bfa86404a2e9ffe67d47c587ef7a97a7f456b863b4d02cfc6928973ab5b1cb39",
+            "6d3e71882c3b83b156bb14e0ab184aa9fb728068d3ae9fac421187ae0b2f34c6",
+            "9b2db89cb0e8fa3cc7608b4d6cc1dec0114e0b9ff4080bea12b134f489ab2bbc",
+            "976d3a4e9d23326dc0baa9fa560b7c4e53f42864f508483a6473b6a11079b2db",
+            "1b766e9ceb71ba6c01dcd46e0af462cd4cfa652ae5017d4555b8eeefe36e1932",
+        ],
+        [
+            "e283871239837e13b95f789e6e1af63bf61c918c992e62bca040d64cad1fc2ef",
+            "74ccd8a62fba0e667c50929a53f78c21b8ff0c3c737b0b40b1750b2302b0bde8",
+            "29074e21f3a0ef88b9efdf10d06aa4c295cc1671f758ca0e4cd108803d0f2614",
+            "ad5e887eb2b380b8d8280ad6e5ff8a60f4d26243e0124c2f31a297b5d0835de2",
+            "b804cf88af0c2eff8bbbfb3660ebb3294138e9d3ebd458884e19818061dacff0",
+            "35fb60f5ca0f3ca08542fb3cc641c8263a2cab7a90ee6a5e1583fac2bb6f6bd1",
+            "ee59d81bc9db1055cc0ed97b159d8784af04e98511d0a9a407b99bb292572e96",
+        ],
+        [
+            "a3d2d3b7596f6592ce98b4bfe10d41837f10027a90d7bb75349490018cf72d07",
+            "322f80371bf6e044bc49391d97c1714ab87f990b949bc178cb7c43b7c22d89e1",
+            "3c15d54a5cc6b9f09de8457e873eb3deb1fceb54b0b295da6050294fae7fd999",
+            "24fc90e1da13f17ef9fe84cc96b9471ed1aaac17e3a4bae33a115df4e5834f18",
+            "85b957d92766235e7c880ac5447cfbe97f3cb499f486d1e43bcb5c2ff9608a1a",
+            "d7c562370af617b581c84a2468cc8bd50bb1cbf322de41b7887ce07c0e5884ca",
+            "b46d9f2d8c4bf83546ff178f1d78937c008d64e8ecc5cbb825cb21d94d670d89",
+        ],
+        [
+            "53a0e8a8fe93db01e7ae94e1a9882a102ebd079b3a535827d583626c272d280d",
+            "1bcec4570e1ec2436596b8ded58f60c3b1ebc6a403bc5543040ba82963057244",
+            "8af62a4c683f096b28558320737bf83b9959a46ad2521004ef74cf85e67494e1",
+            "5d833e8d24cc7a402d7ee7ec852a3587cddeb48358cea71b0bedb8fabe84e0c4",
+            "3360d699222f21840827cf698d7cb635bee57dc80cd7733b682d41b55b666e22",
+            "18caaf7b663507a8bcd992b836dec9dc5703c080af5e51dfa3a9a7c387182604",
+            "77c68928ac3b88d985fb43fb615fb7ff45c18ba5c81af796c613dfa98352d29c",
+        ],
+    ];
+
+    fn signer<T: Curve>(curve: T) -> Signature<T> {
+        let mut signer = Signature {
+            curve,
+            secret: BigUint::ZERO,
+            public_key: CurvePoint::Infinity,
+        };
+        signer.generate_keypair();
+        signer
+    }
+
+    #[test]
+    fn should_verify_signed_message() {
+        let signer = signer(Secp256k1);
+        let signature = signer.sign_message::<Sha256>(b"hello");
+
+        let hash = hash_message::<Sha256, _>(b"hello", &Secp256k1);
+        assert!(verify_prehashed(
+            &hash,
+            &signature,
+            &signer.to_public_key().unwrap()
+        ));
+
+        let other_hash = hash_message::<Sha256, _>(b"hellO", &Secp256k1);
+        assert!(!verify_prehashed(
+            &other_hash,
+            &signature,
+            &signer.to_public_key().unwrap()
+        ));
+    }
+
+    #[test]
+    fn should_agree_between_message_and_prehashed_verification() {
+        let signer = signer(P256);
+        let signature = signer.sign_message::<Sha256>(b"hello");
+        let hash = hash_message::<Sha256, _>(b"hello", &P256);
+
+        assert!(verify_message::<Sha256, _>(
+            b"hello",
+            &signature,
+            &signer.to_public_key().unwrap()
+        ));
+        assert!(verify_prehashed(
+            &hash,
+            &signature,
+            &signer.to_public_key().unwrap()
+        ));
+
+        // Hashing the digest again is the mistake the split is meant to prevent
+        let digest = Sha256::digest(b"hello");
+        assert!(!verify_message::<Sha256, _>(
+            &digest,
+            &signature,
+            &signer.to_public_key().unwrap()
+        ));
+        assert!(!verify_message::<Sha256, _>(
+            b"hellO",
+            &signature,
+            &signer.to_public_key().unwrap()
+        ));
+    }
+
+    #[test]
+    fn should_verify_on_a_curve_with_nonzero_a() {
+        let signer = signer(P256);
+        let hash = hash_message::<Sha256, _>(b"hello", &P256);
+        let signature = signer.sign(&hash);
+
+        assert!(verify_prehashed(
+            &hash,
+            &signature,
+            &signer.to_public_key().unwrap()
+        ));
+    }
+
+    #[test]
+    fn should_reject_out_of_range_components() {
+        let signer = signer(Secp256k1);
+        let hash = BigUint::from(42_u8);
+        let (r, s) = signer.sign(&hash).into_parts();
+        let n = Secp256k1.order();
+
+        for signature in [
+            EcdsaSignature::new(BigUint::ZERO, s.clone()),
+            EcdsaSignature::new(r.clone(), BigUint::ZERO),
+            EcdsaSignature::new(&r + &n, s.clone()),
+            EcdsaSignature::new(r.clone(), &s + &n),
+        ] {
+            assert!(!verify_prehashed(
+                &hash,
+                &signature,
+                &signer.to_public_key().unwrap()
+            ));
+        }
+    }
+
+    #[test]
+    fn should_sign_streamed_chunks_like_the_whole_message() {
+        let keypair = signer(P256);
+        let message: Vec<u8> = (0..=255_u8).cycle().take(10_000).collect();
+        let expected = keypair.sign_with_rng(
+            &hash_message::<Sha256, _>(&message, &P256),
+            &mut StdRng::seed_from_u64(7),
+        );
+
+        for chunk_size in [1, 64, 1000, message.len()] {
+            let mut streamed = Signer::<Sha256, _>::new(&keypair);
+            for chunk in message.chunks(chunk_size) {
+                streamed.update(chunk);
+            }
+            let signature = streamed.finalize_and_sign_with_rng(&mut StdRng::seed_from_u64(7));
+            assert_eq!(signature, expected, "chunks of {chunk_size} bytes");
+        }
+
+        let mut streamed = Signer::<Sha512, _>::new(&keypair);
+        streamed.update(&message[..5000]);
+        streamed.update(&message[5000..]);
+        let signature = streamed.finalize_and_sign();
+        assert!(verify_message::<Sha512, _>(
+            &message,
+            &signature,
+            &keypair.to_public_key().unwrap()
+        ));
+    }
+
+    #[test]
+    fn should_verify_against_either_sec1_form() {
+        let keypair = signer(P256);
+        let public_key = keypair.to_public_key().unwrap();
+        let hash = BigUint::from(42_u8);
+        let signature = keypair.sign(&hash);
+
+        for compressed in [true, false] {
+            let bytes = public_key.to_sec1(compressed);
+            assert_eq!(
+                verify_with_pubkey_bytes(&hash, &signature, &bytes, P256),
+                Ok(true)
+            );
+            assert_eq!(
+                verify_with_pubkey_bytes(&(&hash + 1_u8), &signature, &bytes, P256),
+                Ok(false)
+            );
+        }
+
+        let mut off_curve = public_key.to_sec1(false);
+        off_curve[64] ^= 1;
+        assert_eq!(
+            verify_with_pubkey_bytes(&hash, &signature, &off_curve, P256),
+            Err(Error::PointNotOnCurve)
+        );
+        assert_eq!(
+            verify_with_pubkey_bytes(&hash, &signature, &[0x05; 33], P256),
+            Err(Error::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn should_report_each_rejection_reason() {
+        let signer = Signature::from_secret_hex(Secp256k1, "c0ffee").unwrap();
+        let public_key = signer.to_public_key().unwrap();
+        let hash = BigUint::from(42_u8);
+        let (r, s) = signer.sign(&hash).into_parts();
+        let n = Secp256k1.order();
+
+        let cases = [
+            (
+                hash.clone(),
+                EcdsaSignature::new(r.clone(), s.clone()),
+                Ok(()),
+            ),
+            (
+                hash.clone(),
+                EcdsaSignature::new(n.clone(), s.clone()),
+                Err(VerifyError::ROutOfRange),
+            ),
+            (
+                hash.clone(),
+                EcdsaSignature::new(r.clone(), BigUint::ZERO),
+                Err(VerifyError::SOutOfRange),
+            ),
+            (
+                &hash + 1_u8,
+                EcdsaSignature::new(r.clone(), s.clone()),
+                Err(VerifyError::RMismatch),
+            ),
+            // z = -rd makes u1 + u2 d vanish, so u1 G + u2 Q = (u1 + u2 d) G = O
+            (
+                &n - (&r * &signer.secret) % &n,
+                EcdsaSignature::new(r.clone(), s.clone()),
+                Err(VerifyError::InfinityResult),
+            ),
+        ];
+
+        for (hash, signature, expected) in cases {
+            assert_eq!(signature.verify_detailed(&hash, &public_key), expected);
+            assert_eq!(signature.verify(&hash, &public_key), expected.is_ok());
+        }
+    }
+
+    #[test]
+    fn should_reject_s_without_inverse_for_composite_order() {
+        // The toy curve with a wrong, composite order 18: s = 2 shares the factor 2 with it
+        let curve = GenericCurve {
+            n: BigUint::from(18_u8),
+            ..GenericCurve::toy()
+        };
+        let public_key = PublicKey::new(curve, GenericCurve::toy().generator_point()).unwrap();
+        let signature = EcdsaSignature::new(BigUint::from(1_u8), BigUint::from(2_u8));
+
+        assert_eq!(
+            signature.verify_detailed(&BigUint::from(5_u8), &public_key),
+            Err(VerifyError::SNotInvertible)
+        );
+    }
+
+    #[test]
+    fn should_match_rfc6979_vector_with_fixed_nonce() {
+        // RFC 6979, appendix A.2.5: P-256 with SHA-256, message "sample"
+        let hex = |value: &[u8]| BigUint::parse_bytes(value, 16).unwrap();
+        let signer = Signature::from_secret_hex(
+            P256,
+            "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+        )
+        .unwrap();
+        let k = hex(b"A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60");
+        let hash = hash_message::<Sha256, _>(b"sample", &P256);
+
+        let signature = signer.sign_with_nonce(&hash, k).unwrap();
+        assert_eq!(
+            signature,
+            EcdsaSignature::new(
+                hex(b"EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716"),
+                hex(b"F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8")
+            )
+        );
+        assert!(verify_prehashed(
+            &hash,
+            &signature,
+            &signer.to_public_key().unwrap()
+        ));
+    }
+
+    #[test]
+    fn should_match_nist_cavp_vectors() {
+        let hex = |value: &str| BigUint::parse_bytes(value.as_bytes(), 16).unwrap();
+
+        for [d, qx, qy, k, hash, r, s] in CAVP_P256_SHA256 {
+            let signer = Signature::from_secret_hex(P256, d).unwrap();
+            assert_eq!(
+                signer.public_key,
+                CurvePoint::Affine {
+                    x: hex(qx),
+                    y: hex(qy)
+                }
+            );
+
+            let hash = hex(hash);
+            let signature = signer.sign_with_nonce(&hash, hex(k)).unwrap();
+            assert_eq!(signature, EcdsaSignature::new(hex(r), hex(s)));
+            assert!(verify_prehashed(
+                &hash,
+                &signature,
+                &signer.to_public_key().unwrap()
+            ));
+        }
+    }
+
+    #[test]
+    fn should_refuse_to_reuse_nonce_for_another_message() {
+        let signer = signer(Secp256k1);
+        let k = BigUint::from(0x5eed_u16);
+        let (first, second) = (BigUint::from(1_u8), BigUint::from(2_u8));
+        let mut context = SigningContext::new(&signer);
+
+        let signature = context.sign_with_nonce(&first, k.clone()).unwrap();
+        assert_eq!(
+            context.sign_with_nonce(&second, k.clone()),
+            Err(Error::NonceReuse)
+        );
+        // Signing the same message again doesn't leak anything
+        assert_eq!(context.sign_with_nonce(&first, k.clone()), Ok(signature));
+        // A fresh nonce is fine, and so is the old one in a new session
+        assert!(context.sign(&second).is_ok());
+        assert!(SigningContext::new(&signer)
+            .sign_with_nonce(&second, k)
+            .is_ok());
+    }
+
+    #[test]
+    fn should_reject_degenerate_nonce() {
+        let signer = signer(Secp256k1);
+        let hash = BigUint::from(42_u8);
+
+        assert_eq!(
+            signer.sign_with_nonce(&hash, BigUint::ZERO),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(
+            signer.sign_with_nonce(&hash, Secp256k1.order()),
+            Err(Error::ScalarOutOfRange)
+        );
+    }
+
+    #[test]
+    fn should_expose_components_and_verify() {
+        let signer = signer(P256);
+        let hash = hash_message::<Sha256, _>(b"hello", &P256);
+        let signature = signer.sign(&hash);
+
+        assert!(signature.verify(&hash, &signer.to_public_key().unwrap()));
+        assert!(!signature.verify(&(&hash + 1_u8), &signer.to_public_key().unwrap()));
+
+        // Swapping the components is exactly the mistake the type is meant to prevent
+        let (r, s) = signature.clone().into_parts();
+        assert_eq!((&r, &s), (signature.r(), signature.s()));
+        let swapped = EcdsaSignature::new(s, r);
+        assert!(!swapped.verify(&hash, &signer.to_public_key().unwrap()));
+    }
+
+    #[test]
+    fn should_round_trip_compact_signature() {
+        fn check<T: Curve>(curve: T) {
+            let signer = signer(curve);
+            let signature = signer.sign(&BigUint::from(42_u8));
+
+            let bytes = signature.to_compact(&signer.curve);
+            assert_eq!(bytes.len(), 64);
+            assert_eq!(
+                EcdsaSignature::from_compact(&bytes, &signer.curve),
+                Ok(signature)
+            );
+        }
+
+        check(Secp256k1);
+        check(P256);
+
+        // Small components are left-padded
+        let signature = EcdsaSignature::new(BigUint::from(1_u8), BigUint::from(2_u8));
+        let bytes = signature.to_compact(&Secp256k1);
+        assert_eq!(bytes[31], 1);
+        assert_eq!(bytes[63], 2);
+        assert_eq!(
+            EcdsaSignature::from_compact(&bytes, &Secp256k1),
+            Ok(signature)
+        );
+    }
+
+    #[test]
+    fn should_pad_compact_signature_to_order_size() {
+        // A one-byte field with a two-byte order
+        let curve = GenericCurve {
+            n: BigUint::from(300_u16),
+            ..GenericCurve::toy()
+        };
+        let signature = EcdsaSignature::new(BigUint::from(257_u16), BigUint::from(299_u16));
+
+        let bytes = signature.to_compact(&curve);
+        assert_eq!(bytes, [0x01, 0x01, 0x01, 0x2b]);
+        assert_eq!(EcdsaSignature::from_compact(&bytes, &curve), Ok(signature));
+    }
+
+    #[test]
+    fn should_reject_malformed_compact_signature() {
+        let bytes =
+            EcdsaSignature::new(BigUint::from(1_u8), BigUint::from(2_u8)).to_compact(&Secp256k1);
+
+        for length in [0, 32, 63, 65] {
+            let mut resized = bytes.clone();
+            resized.resize(length, 0);
+            assert_eq!(
+                EcdsaSignature::from_compact(&resized, &Secp256k1),
+                Err(Error::InvalidSignature)
+            );
+        }
+        assert_eq!(
+            EcdsaSignature::from_compact(&[0xff; 64], &Secp256k1),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(
+            EcdsaSignature::from_compact(&[0; 64], &Secp256k1),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn should_round_trip_der_signature() {
+        fn check<T: Curve>(curve: T) {
+            let signer = signer(curve);
+            let signature = signer.sign(&BigUint::from(42_u8));
+
+            let bytes = signature.to_der();
+            assert_eq!(bytes[0], 0x30);
+            assert_eq!(usize::from(bytes[1]), bytes.len() - 2);
+            assert_eq!(
+                EcdsaSignature::from_der(&bytes, &signer.curve),
+                Ok(signature)
+            );
+        }
+
+        check(Secp256k1);
+        check(P256);
+
+        // Minimal integers, with a zero byte in front of a set top bit
+        let signature = EcdsaSignature::new(BigUint::from(1_u8), BigUint::from(0x80_u8));
+        let bytes = signature.to_der();
+        assert_eq!(
+            bytes,
+            [0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x02, 0x00, 0x80]
+        );
+        assert_eq!(EcdsaSignature::from_der(&bytes, &Secp256k1), Ok(signature));
+    }
+
+    #[test]
+    fn should_reject_malformed_der_signature() {
+        let cases: [&[u8]; 9] = [
+            &[],
+            // Wrong outer tag
+            &[0x31, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01],
+            // Outer length too long, too short
+            &[0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01],
+            &[0x30, 0x05, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01],
+            // Trailing byte
+            &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01, 0x00],
+            // Negative s
+            &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x81],
+            // Non-minimal s
+            &[0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x02, 0x00, 0x01],
+            // Empty s
+            &[0x30, 0x05, 0x02, 0x01, 0x01, 0x02, 0x00],
+            // Long form for a short length
+            &[0x30, 0x81, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01],
+        ];
+
+        for bytes in cases {
+            assert_eq!(
+                EcdsaSignature::from_der_lenient(bytes),
+                Err(Error::InvalidSignature),
+                "{bytes:02x?}"
+            );
+            assert_eq!(
+                EcdsaSignature::from_der(bytes, &Secp256k1),
+                Err(Error::InvalidSignature),
+                "{bytes:02x?}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_non_canonical_components_only_in_strict_mode() {
+        let order = Secp256k1.order();
+        let r = BigUint::from(1_u8);
+
+        for s in [order.clone(), &order + 1_u8] {
+            let signature = EcdsaSignature::new(r.clone(), s);
+
+            let compact = signature.to_compact(&Secp256k1);
+            assert_eq!(
+                EcdsaSignature::from_compact(&compact, &Secp256k1),
+                Err(Error::InvalidSignature)
+            );
+            assert_eq!(
+                EcdsaSignature::from_compact_lenient(&compact, &Secp256k1),
+                Ok(signature.clone())
+            );
+
+            let der = signature.to_der();
+            assert_eq!(
+                EcdsaSignature::from_der(&der, &Secp256k1),
+                Err(Error::InvalidSignature)
+            );
+            assert_eq!(
+                EcdsaSignature::from_der_lenient(&der),
+                Ok(signature.clone())
+            );
+
+            // Verification still rejects them
+            let public_key = PublicKey::new(Secp256k1, Secp256k1.generator_point()).unwrap();
+            assert!(!signature.verify(&BigUint::from(1_u8), &public_key));
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "rayon"))]
+    fn should_verify_batch_on_curve_that_is_not_sync() {
+        use core::{cell::Cell, marker::PhantomData};
+
+        use super::verify_batch;
+
+        /// secp256k1, but `!Sync` like a curve that caches its parameters in a `Cell`
+        #[derive(Clone)]
+        struct Unsync(PhantomData<Cell<()>>);
+
+        impl Curve for Unsync {
+            fn generator_point(&self) -> CurvePoint {
+                Secp256k1.generator_point()
+            }
+
+            fn prime_modulus(&self) -> BigUint {
+                Secp256k1.prime_modulus()
+            }
+
+            fn a(&self) -> BigUint {
+                Secp256k1.a()
+            }
+
+            fn b(&self) -> BigUint {
+                Secp256k1.b()
+            }
+
+            fn order(&self) -> BigUint {
+                Secp256k1.order()
+            }
+
+            fn identity(&self) -> CurvePoint {
+                CurvePoint::Infinity
+            }
+        }
+
+        let signer = signer(Unsync(PhantomData));
+        let hash = BigUint::from(7_u8);
+        let signature = signer.sign(&hash);
+        let public_key = signer.to_public_key().unwrap();
+
+        assert_eq!(
+            verify_batch(&[
+                (hash.clone(), signature.clone(), public_key.clone()),
+                (hash + 1_u8, signature, public_key),
+            ]),
+            [true, false]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn should_verify_mixed_batch_in_parallel() {
+        use super::verify_batch;
+
+        let signers = [signer(Secp256k1), signer(Secp256k1)];
+
+        let batch: Vec<_> = (0..16_u8)
+            .map(|i| {
+                let signer = &signers[usize::from(i % 2)];
+                let hash = BigUint::from(i) << 200;
+                let mut signature = signer.sign(&hash);
+                match i % 4 {
+                    // Signed by the other key
+                    1 => return (hash, signature, signers[0].to_public_key().unwrap()),
+                    // Tampered s
+                    3 => {
+                        signature = EcdsaSignature::new(signature.r().clone(), signature.s() + 1_u8)
+                    }
+                    _ => {}
+                }
+                (hash, signature, signer.to_public_key().unwrap())
+            })
+            .collect();
+
+        let sequential: Vec<_> = batch
+            .iter()
+            .map(|(hash, signature, public_key)| verify_prehashed(hash, signature, public_key))
+            .collect();
+        assert_eq!(verify_batch(&batch), sequential);
+        assert_eq!(sequential, (0..16).map(|i| i % 2 == 0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn should_check_component_ranges_without_verifying() {
+        let n = Secp256k1.order();
+        let one = BigUint::from(1_u8);
+
+        for (component, expected) in [
+            (BigUint::ZERO, false),
+            (one.clone(), true),
+            (&n - 1_u8, true),
+            (n.clone(), false),
+        ] {
+            assert_eq!(
+                is_well_formed_signature(&component, &one, &Secp256k1),
+                expected
+            );
+            assert_eq!(
+                is_well_formed_signature(&one, &component, &Secp256k1),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn should_verify_batch_with_repeated_keys() {
+        use super::verify_batch;
+
+        let signers = [signer(Secp256k1), signer(Secp256k1), signer(Secp256k1)];
+
+        let batch: Vec<_> = (0..12_u8)
+            .map(|i| {
+                let signer = &signers[usize::from(i % 3)];
+                // A zero hash makes u1 vanish, leaving only the Q part of the table
+                let hash = BigUint::from(i) << 200;
+                let mut signature = signer.sign(&hash);
+                match i % 4 {
+                    // Signed by another key
+                    1 => {
+                        let other = &signers[usize::from(i + 1) % 3];
+                        return (hash, signature, other.to_public_key().unwrap());
+                    }
+                    // Tampered r
+                    3 => {
+                        signature = EcdsaSignature::new(signature.r() + 1_u8, signature.s().clone())
+                    }
+                    _ => {}
+                }
+                (hash, signature, signer.to_public_key().unwrap())
+            })
+            .collect();
+
+        let naive: Vec<_> = batch
+            .iter()
+            .map(|(hash, signature, public_key)| verify_prehashed(hash, signature, public_key))
+            .collect();
+        assert_eq!(verify_batch(&batch), naive);
+        assert_eq!(naive, (0..12).map(|i| i % 2 == 0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn should_not_coalesce_keys_across_curves() {
+        use super::verify_batch;
+
+        // Same equation and order, but the second curve uses 2G = (6, 3) as its generator
+        let b = |value: u8| BigUint::from(value);
+        let curve = GenericCurve::toy();
+        let other = GenericCurve {
+            gx: b(6),
+            gy: b(3),
+            ..GenericCurve::toy()
+        };
+
+        let point = curve.calculate_public_key(b(7));
+        let signature = Signature {
+            curve: curve.clone(),
+            secret: b(7),
+            public_key: point.clone(),
+        }
+        .sign_with_nonce(&b(5), b(3))
+        .unwrap();
+        let batch = [
+            (
+                b(5),
+                signature.clone(),
+                PublicKey::new(curve, point.clone()).unwrap(),
+            ),
+            (b(5), signature, PublicKey::new(other, point).unwrap()),
+        ];
+
+        assert_eq!(verify_batch(&batch), [true, false]);
+    }
+
+    #[test]
+    fn should_keep_digest_when_not_longer_than_order() {
+        let digest = Sha256::digest(b"hello");
+        let expected = BigUint::from_bytes_be(&digest) % P256.order();
+
+        assert_eq!(hash_message::<Sha256, _>(b"hello", &P256), expected);
+    }
+
+    #[test]
+    fn should_truncate_digest_longer_than_order() {
+        // SHA-512 produces 512 bits, secp256k1's order has 256: keep the leftmost 256 bits
+        let digest = Sha512::digest(b"hello");
+        let expected = BigUint::from_bytes_be(&digest[..32]) % Secp256k1.order();
+        assert_eq!(hash_message::<Sha512, _>(b"hello", &Secp256k1), expected);
+
+        // The order 19 has 5 bits: keep the leftmost 5 bits of the SHA-256 digest
+        let digest = Sha256::digest(b"hello");
+        let expected = BigUint::from(digest[0] >> 3) % 19_u8;
+        assert_eq!(
+            hash_message::<Sha256, _>(b"hello", &GenericCurve::toy()),
+            expected
+        );
+    }
+
+    #[test]
+    fn should_truncate_digest_when_signing() {
+        // A 64-byte digest is longer than the 32 bytes of secp256k1's order
+        let large_signer = signer(Secp256k1);
+        let digest = Sha512::digest(b"hello");
+        let signature = large_signer.sign_digest(&digest);
+
+        let truncated = BigUint::from_bytes_be(&digest[..32]) % Secp256k1.order();
+        assert_eq!(digest_to_scalar(&digest, &Secp256k1), truncated);
+        assert!(signature.verify(&truncated, &large_signer.to_public_key().unwrap()));
+        assert!(verify_message::<Sha512, _>(
+            b"hello",
+            &signature,
+            &large_signer.to_public_key().unwrap()
+        ));
+
+        // The order 19 has 5 bits, so only the top 5 bits of the first byte count
+        let small_signer = signer(GenericCurve::toy());
+        let mut digest = [0xff_u8; 32];
+        digest[0] = 0b0001_1111;
+        let signature = small_signer.sign_digest(&digest);
+        assert_eq!(
+            digest_to_scalar(&digest, &GenericCurve::toy()),
+            BigUint::from(3_u8)
+        );
+        assert!(signature.verify(&BigUint::from(3_u8), &small_signer.to_public_key().unwrap()));
+    }
+
+    #[test]
+    fn should_sign_and_verify_on_small_curve() {
+        let signer = signer(GenericCurve::toy());
+
+        for value in 0..19_u8 {
+            let hash = BigUint::from(value);
+            let signature = signer.sign(&hash);
+            assert!(verify_prehashed(
+                &hash,
+                &signature,
+                &signer.to_public_key().unwrap()
+            ));
+        }
+    }
+}
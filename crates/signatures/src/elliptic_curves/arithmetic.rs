@@ -1,6 +1,7 @@
 use num_bigint::BigUint;
 
 use super::curve::{Curve, CurvePoint};
+use crate::error::Error;
 
 /// Adds two points on an elliptic curve.
 ///
@@ -26,7 +27,8 @@ use super::curve::{Curve, CurvePoint};
 /// - If \( P = -Q \) (i.e., \( x_1 = x_2 \) but \( y_1 \neq y_2 \)), the result is the point at infinity (\( O \)).
 ///
 /// ### Modular Arithmetic:
-/// All calculations are performed modulo the prime modulus of the curve.
+/// All calculations are performed modulo the prime modulus of the curve. The inputs are reduced with
+/// [`Curve::normalize`] first, so coordinates \( \geq p \) behave like their reduced forms.
 ///
 /// # Parameters
 /// - `first`: The first elliptic curve point (\( P \)).
@@ -52,7 +54,15 @@ use super::curve::{Curve, CurvePoint};
 /// let result = add_two_points(point1, point2, &curve);
 /// println!("{:?}", result); // CurvePoint::Affine { x: ..., y: ... }
 /// ```
-pub fn add_two_points<T: Curve>(first: CurvePoint, second: CurvePoint, curve: &T) -> CurvePoint {
+pub fn add_two_points<T: Curve + ?Sized>(
+    first: CurvePoint,
+    second: CurvePoint,
+    curve: &T,
+) -> CurvePoint {
+    // 0) Reduce the coordinates, so the equality checks below see canonical values
+    let first = curve.normalize(first);
+    let second = curve.normalize(second);
+
     // 1) Handle identity (point at infinity) cases
     if first.is_infinity() {
         return second;
@@ -78,31 +88,23 @@ pub fn add_two_points<T: Curve>(first: CurvePoint, second: CurvePoint, curve: &T
     let p = curve.prime_modulus(); // We'll reuse this below
 
     // 4) Compute slope (lambda)
-    let (numerator, denominator) = if x1 == x2 && y1 == y2 {
+    if x1 == x2 && y1 == y2 {
         // Doubling case
-        // If y == 0, 2P => Infinity
-        if y1 == BigUint::ZERO {
-            return CurvePoint::Infinity;
-        }
-        let x1_sq = x1.modpow(&BigUint::from(2_u8), &p);
-        let numerator = (BigUint::from(3u8) * x1_sq + curve.a()) % &p;
-        let denominator = (BigUint::from(2u8) * &y1) % &p;
-        (numerator, denominator)
-    } else {
-        // Addition case
-        // (y2 - y1) / (x2 - x1)
-        let numerator = mod_sub(&y2, &y1, &p);
-        let denominator = mod_sub(&x2, &x1, &p);
-        (numerator, denominator)
-    };
+        return curve.double(CurvePoint::Affine { x: x1, y: y1 });
+    }
+    // Addition case
+    // (y2 - y1) / (x2 - x1)
+    let numerator = mod_sub(&y2, &y1, &p);
+    let denominator = mod_sub(&x2, &x1, &p);
 
 
-    let denom_inv = mod_inv(denominator.clone(), &p);  // might panic if denominator=0
+    // x1 != x2 here, so the denominator is non-zero and invertible
+    let denom_inv = mod_inv(denominator.clone(), &p);
 
-    let lambda = (&numerator * &denom_inv) % &p;
+    let lambda = mod_mul(&numerator, &denom_inv, &p);
 
     // 5) Compute x3 = (lambda^2 - x1 - x2) mod p
-    let lambda_sq = lambda.modpow(&BigUint::from(2_u8), &p);
+    let lambda_sq = mod_mul(&lambda, &lambda, &p);
 
     // stepwise: x3 = ( (lambda_sq - x1) - x2 ) mod p
     let mut x3 = mod_sub(&lambda_sq, &x1, &p);
@@ -114,14 +116,155 @@ pub fn add_two_points<T: Curve>(first: CurvePoint, second: CurvePoint, curve: &T
     // t1 = (x1 - x3) mod p
     let t1 = mod_sub(&x1, &x3, &p);
     // t2 = lambda * t1 (mod p)
-    let t2 = (&lambda * &t1) % &p;
+    let t2 = mod_mul(&lambda, &t1, &p);
     // y3 = (t2 - y1) mod p
     let y3 = mod_sub(&t2, &y1, &p);
 
 
-    let result = CurvePoint::Affine { x: x3, y: y3 };
+    CurvePoint::Affine { x: x3, y: y3 }
+}
+
+/// Computes \( \sum_i a_i P_i \) for a list of `(scalar, point)` pairs.
+///
+/// Uses Strauss's algorithm (interleaved double-and-add, also known as Shamir's trick): the scalars
+/// are scanned together from the most significant bit down, so the result is doubled only once per
+/// bit instead of once per bit per pair.
+///
+/// # Examples
+/// ```rust
+/// use num_bigint::BigUint;
+/// use signatures::elliptic_curves::arithmetic::multi_scalar_mul;
+/// use signatures::elliptic_curves::curve::Curve;
+/// use signatures::elliptic_curves::secp256k1::Secp256k1;
+///
+/// let curve = Secp256k1;
+/// let g = curve.generator_point();
+/// // 2G + 3G = 5G
+/// let result = multi_scalar_mul(&[(BigUint::from(2_u8), g.clone()), (BigUint::from(3_u8), g)], &curve);
+/// assert_eq!(result, curve.calculate_public_key(BigUint::from(5_u8)));
+/// ```
+pub fn multi_scalar_mul<T: Curve>(pairs: &[(BigUint, CurvePoint)], curve: &T) -> CurvePoint {
+    let max_bits = pairs
+        .iter()
+        .map(|(scalar, _)| scalar.bits())
+        .max()
+        .unwrap_or(0);
+
+    let mut result = CurvePoint::Infinity;
+    for i in (0..max_bits).rev() {
+        result = curve.double(result);
+        for (scalar, point) in pairs {
+            if scalar.bit(i) {
+                result = add_two_points(result, point.clone(), curve);
+            }
+        }
+    }
+
     result
 }
+
+/// Multiplies `point` by `scalar` with right-to-left double-and-add, see [`Curve::scalar_mul`].
+///
+/// Takes the curve as a trait object, so it works for `dyn Curve` (where the `Sized`-bound trait method is not
+/// available) and is compiled once rather than for every curve type. `Curve::scalar_mul` delegates here.
+///
+/// # Examples
+/// ```rust
+/// use num_bigint::BigUint;
+/// use signatures::elliptic_curves::arithmetic::scalar_mul;
+/// use signatures::elliptic_curves::curve::Curve;
+/// use signatures::elliptic_curves::secp256k1::Secp256k1;
+///
+/// let curve: &dyn Curve = &Secp256k1;
+/// let result = scalar_mul(&BigUint::from(5_u8), &curve.generator_point(), curve);
+/// assert_eq!(result, Secp256k1.calculate_public_key(BigUint::from(5_u8)));
+/// ```
+pub fn scalar_mul(scalar: &BigUint, point: &CurvePoint, curve: &dyn Curve) -> CurvePoint {
+    let mut result = curve.identity();
+    let mut current = point.clone();
+
+    for bit in ScalarBits::lsb_first(scalar) {
+        if bit {
+            result = add_two_points(result, current.clone(), curve);
+        }
+        current = curve.double(current);
+    }
+
+    result
+}
+
+/// Iterates over the bits of a scalar, from the least or the most significant one, reading them in place.
+///
+/// Only the `bits()` significant bits are yielded, so zero has none. The shared building block of the scalar
+/// multiplication strategies: right-to-left double-and-add walks the bits LSB first, while left-to-right
+/// methods like the Montgomery ladder walk them MSB first.
+///
+/// # Examples
+/// ```rust
+/// use num_bigint::BigUint;
+/// use signatures::elliptic_curves::arithmetic::ScalarBits;
+///
+/// let scalar = BigUint::from(0b110_u8);
+/// assert!(ScalarBits::lsb_first(&scalar).eq([false, true, true]));
+/// assert!(ScalarBits::msb_first(&scalar).eq([true, true, false]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScalarBits<'a> {
+    scalar: &'a BigUint,
+    // The bits in [low, high) that are yet to be yielded
+    low: u64,
+    high: u64,
+    msb_first: bool,
+}
+
+impl<'a> ScalarBits<'a> {
+    /// Yields bit 0 first
+    pub fn lsb_first(scalar: &'a BigUint) -> Self {
+        Self {
+            scalar,
+            low: 0,
+            high: scalar.bits(),
+            msb_first: false,
+        }
+    }
+
+    /// Yields the most significant (always set) bit first
+    pub fn msb_first(scalar: &'a BigUint) -> Self {
+        Self {
+            scalar,
+            low: 0,
+            high: scalar.bits(),
+            msb_first: true,
+        }
+    }
+}
+
+impl Iterator for ScalarBits<'_> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.low == self.high {
+            return None;
+        }
+
+        let index = if self.msb_first {
+            self.high -= 1;
+            self.high
+        } else {
+            self.low += 1;
+            self.low - 1
+        };
+        Some(self.scalar.bit(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.high - self.low) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for ScalarBits<'_> {}
+
 /// Computes the modular inverse of a number.
 ///
 /// This function calculates the modular inverse of `value` modulo `modulus` using Fermat's Little Theorem:
@@ -133,24 +276,125 @@ pub fn add_two_points<T: Curve>(first: CurvePoint, second: CurvePoint, curve: &T
 ///
 /// # Returns
 /// - The modular inverse of `value` modulo `modulus`.
-fn mod_inv(value: BigUint, modulus: &BigUint) -> BigUint {
+pub(crate) fn mod_inv(value: BigUint, modulus: &BigUint) -> BigUint {
     value.modpow(&(modulus - BigUint::from(2_u8)), modulus)
 }
 
-fn mod_sub(a: &BigUint, b: &BigUint, p: &BigUint) -> BigUint {
+/// Computes the modular inverse of `value` modulo the prime `modulus`, rejecting zero.
+///
+/// Meant for public values. Inverting a secret, such as an ECDSA nonce, is what `constant_time::ct_mod_inv` is
+/// for.
+///
+/// # Errors
+/// - `Error::NotInvertible` if `value` is a multiple of `modulus`.
+pub fn try_mod_inv(value: &BigUint, modulus: &BigUint) -> Result<BigUint, Error> {
+    let value = value % modulus;
+    if value == BigUint::ZERO {
+        return Err(Error::NotInvertible);
+    }
+    Ok(mod_inv(value, modulus))
+}
+
+/// Computes a square root of `value` modulo the odd prime `modulus`.
+///
+/// Uses the shortcut \( a^{(p+1)/4} \) when \( p \equiv 3 \mod 4 \) (as for secp256k1) and falls back to
+/// Tonelli-Shanks otherwise.
+///
+/// # Returns
+/// - `Some(root)` if `value` is a quadratic residue, `None` otherwise. The second root is `modulus - root`.
+pub fn mod_sqrt(value: &BigUint, modulus: &BigUint) -> Option<BigUint> {
+    let one = BigUint::from(1_u8);
+    let a = value % modulus;
+    if a == BigUint::ZERO {
+        return Some(a);
+    }
+
+    // Euler's criterion: a^((p-1)/2) == 1 iff a is a quadratic residue
+    let p_minus_one = modulus - &one;
+    if a.modpow(&(&p_minus_one >> 1), modulus) != one {
+        return None;
+    }
+
+    if modulus % BigUint::from(4_u8) == BigUint::from(3_u8) {
+        return Some(a.modpow(&((modulus + &one) >> 2), modulus));
+    }
+
+    // Tonelli-Shanks: write p - 1 = q * 2^s with q odd
+    let s = p_minus_one.trailing_zeros().unwrap_or(0);
+    let q = &p_minus_one >> s;
+
+    // Find any quadratic non-residue z
+    let mut z = BigUint::from(2_u8);
+    while z.modpow(&(&p_minus_one >> 1), modulus) != p_minus_one {
+        z += 1_u8;
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, modulus);
+    let mut t = a.modpow(&q, modulus);
+    let mut r = a.modpow(&((&q + &one) >> 1), modulus);
+
+    while t != one {
+        // Find the least i such that t^(2^i) == 1
+        let mut i = 0;
+        let mut t_pow = t.clone();
+        while t_pow != one {
+            t_pow = (&t_pow * &t_pow) % modulus;
+            i += 1;
+        }
+
+        let b = c.modpow(&(BigUint::from(1_u8) << (m - i - 1)), modulus);
+        m = i;
+        c = (&b * &b) % modulus;
+        t = (&t * &c) % modulus;
+        r = (&r * &b) % modulus;
+    }
+
+    Some(r)
+}
+
+/// Negates a point on the curve: \( -(x, y) = (x, -y) \).
+pub fn negate_point<T: Curve + ?Sized>(point: CurvePoint, curve: &T) -> CurvePoint {
+    match point {
+        CurvePoint::Infinity => CurvePoint::Infinity,
+        CurvePoint::Affine { x, y } => CurvePoint::Affine {
+            x,
+            y: mod_sub(&BigUint::ZERO, &y, &curve.prime_modulus()),
+        },
+    }
+}
+
+pub(crate) fn mod_sub(a: &BigUint, b: &BigUint, p: &BigUint) -> BigUint {
     // (a - b) mod p
     // = ((a mod p) + p - (b mod p)) mod p
     // to avoid negative intermediates.
     ( (a % p) + p - (b % p) ) % p
 }
 
+/// Computes \( (a + b) \mod p \) for operands that may not be reduced.
+pub(crate) fn mod_add(a: &BigUint, b: &BigUint, p: &BigUint) -> BigUint {
+    (a + b) % p
+}
+
+/// Computes \( (a \cdot b) \mod p \) for operands that may not be reduced.
+pub(crate) fn mod_mul(a: &BigUint, b: &BigUint, p: &BigUint) -> BigUint {
+    (a * b) % p
+}
+
 
 #[cfg(test)]
 mod tests {
-    use crate::elliptic_curves::curve::{Curve, CurvePoint};
+    use crate::elliptic_curves::{
+        curve::{Curve, CurvePoint},
+        secp256k1::Secp256k1,
+    };
 
-    use super::{add_two_points, mod_inv};
+    use super::{
+        add_two_points, mod_add, mod_inv, mod_mul, mod_sqrt, mod_sub, multi_scalar_mul,
+        negate_point, scalar_mul, ScalarBits,
+    };
     use num_bigint::BigUint;
+    use rand::{rngs::StdRng, SeedableRng};
 
     /// A simple test curve with small prime modulus.
     /// Let's define a curve: y^2 = x^3 + a*x + b (mod p).
@@ -173,16 +417,17 @@ mod tests {
             BigUint::from(2u32)
         }
 
+        /// Only used to draw random points, see `random_point`
         fn generator_point(&self) -> CurvePoint {
-            unreachable!("This is not called by the arithmetic functions.")
+            CurvePoint::Affine { x: b(5), y: b(1) }
         }
 
         fn order(&self) -> BigUint {
-            unreachable!("This is not called by the arithmetic functions.")
+            b(19)
         }
 
         fn identity(&self) -> CurvePoint {
-            unreachable!("This is not called by the arithmetic functions.")
+            CurvePoint::Infinity
         }
     }
 
@@ -191,6 +436,71 @@ mod tests {
         BigUint::from(val)
     }
 
+    /// Draws a point of the test curve from a seeded generator, checking it is on the curve
+    fn point(seed: u64) -> CurvePoint {
+        let curve = TestCurve;
+        let point = curve.random_point(&mut StdRng::seed_from_u64(seed));
+        assert!(curve.is_on_curve(&point));
+        point
+    }
+
+    #[test]
+    fn test_scalar_bits() {
+        let values = [
+            BigUint::from(1_u8),
+            BigUint::from(0b1011_0010_u8),
+            BigUint::from(u64::MAX),
+            (BigUint::from(1_u8) << 130) + 5_u8,
+        ];
+
+        for value in values {
+            let expected: Vec<bool> = format!("{:b}", value).chars().map(|c| c == '1').collect();
+
+            let msb_first: Vec<bool> = ScalarBits::msb_first(&value).collect();
+            assert_eq!(msb_first, expected, "value = {:b}", value);
+
+            let mut lsb_first: Vec<bool> = ScalarBits::lsb_first(&value).collect();
+            lsb_first.reverse();
+            assert_eq!(lsb_first, expected, "value = {:b}", value);
+
+            assert_eq!(ScalarBits::lsb_first(&value).len() as u64, value.bits());
+        }
+
+        assert_eq!(ScalarBits::lsb_first(&BigUint::ZERO).next(), None);
+        assert_eq!(ScalarBits::msb_first(&BigUint::ZERO).next(), None);
+    }
+
+    #[test]
+    fn test_mod_add() {
+        let p = b(17);
+        assert_eq!(mod_add(&b(3), &b(5), &p), b(8));
+        // The sum exceeds p
+        assert_eq!(mod_add(&b(16), &b(16), &p), b(15));
+        assert_eq!(mod_add(&b(9), &b(8), &p), b(0));
+        // Unreduced operands
+        assert_eq!(mod_add(&b(40), &b(100), &p), b(140 % 17));
+    }
+
+    #[test]
+    fn test_mod_mul() {
+        let p = b(17);
+        assert_eq!(mod_mul(&b(3), &b(5), &p), b(15));
+        // The product exceeds p
+        assert_eq!(mod_mul(&b(16), &b(16), &p), b(1));
+        assert_eq!(mod_mul(&b(0), &b(16), &p), b(0));
+        // Unreduced operands
+        assert_eq!(mod_mul(&b(40), &b(100), &p), b(4000 % 17));
+    }
+
+    #[test]
+    fn test_mod_sub() {
+        let p = b(17);
+        assert_eq!(mod_sub(&b(5), &b(3), &p), b(2));
+        // The difference is negative before reduction
+        assert_eq!(mod_sub(&b(3), &b(5), &p), b(15));
+        assert_eq!(mod_sub(&b(40), &b(100), &p), b((40 + 17 * 6 - 100) % 17));
+    }
+
     #[test]
     fn test_mod_inv_correctness() {
         // For each a in [1..16], check that mod_inv(a, 17) * a % 17 == 1
@@ -204,12 +514,145 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mod_sqrt_correctness() {
+        // 17 = 1 mod 4 exercises Tonelli-Shanks, 19 = 3 mod 4 exercises the shortcut
+        for m in [17, 19] {
+            let modulus = b(m);
+            for val in 0..m {
+                let val_b = b(val);
+                let is_residue = (0..m).any(|y| (b(y) * b(y)) % &modulus == val_b);
+                match mod_sqrt(&val_b, &modulus) {
+                    Some(root) => assert_eq!((&root * &root) % &modulus, val_b, "val = {}", val),
+                    None => assert!(!is_residue, "val = {} has a root", val),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_negate_point() {
+        let curve = TestCurve;
+        let p_point = point(1);
+        let CurvePoint::Affine { x, y } = p_point.clone() else {
+            unreachable!("Random points are never the identity");
+        };
+
+        let negated = negate_point(p_point.clone(), &curve);
+        assert_eq!(negated, CurvePoint::Affine { x, y: b(17) - y });
+        assert!(curve.is_on_curve(&negated));
+        assert_eq!(
+            add_two_points(p_point, negated, &curve),
+            CurvePoint::Infinity
+        );
+        assert_eq!(
+            negate_point(CurvePoint::Infinity, &curve),
+            CurvePoint::Infinity
+        );
+    }
+
+    #[test]
+    fn test_multi_scalar_mul_matches_individual_products() {
+        let curve = TestCurve;
+        let p_point = point(2);
+        let q_point = point(3);
+        let r_point = negate_point(p_point.clone(), &curve);
+
+        let pairs = [
+            (b(7), p_point),
+            (b(0), q_point.clone()),
+            (b(13), q_point),
+            (b(100), r_point),
+        ];
+
+        let expected = pairs
+            .iter()
+            .fold(CurvePoint::Infinity, |acc, (scalar, point)| {
+                add_two_points(acc, curve.scalar_mul(scalar, point.clone()), &curve)
+            });
+        assert_eq!(multi_scalar_mul(&pairs, &curve), expected);
+    }
+
+    #[test]
+    fn test_multi_scalar_mul_edge_cases() {
+        let curve = TestCurve;
+        let p_point = point(4);
+
+        assert_eq!(multi_scalar_mul(&[], &curve), CurvePoint::Infinity);
+        assert_eq!(
+            multi_scalar_mul(&[(b(0), p_point.clone())], &curve),
+            CurvePoint::Infinity
+        );
+        assert_eq!(
+            multi_scalar_mul(
+                &[(b(1), CurvePoint::Infinity), (b(1), p_point.clone())],
+                &curve
+            ),
+            p_point
+        );
+    }
+
+    #[test]
+    fn test_multi_scalar_mul_through_infinity() {
+        let curve = TestCurve;
+        let generator = curve.generator_point();
+        let order = curve.order();
+
+        // The sum cancels out only after the last bit, when G + (-G) is added
+        assert_eq!(
+            multi_scalar_mul(
+                &[
+                    (b(1), generator.clone()),
+                    (&order - 1_u8, generator.clone())
+                ],
+                &curve
+            ),
+            CurvePoint::Infinity
+        );
+        assert_eq!(
+            multi_scalar_mul(&[(order.clone(), generator.clone())], &curve),
+            CurvePoint::Infinity
+        );
+        // Passing through infinity mid-way doesn't derail the rest of the computation
+        assert_eq!(
+            multi_scalar_mul(&[(order + 3_u8, generator.clone())], &curve),
+            curve.scalar_mul(&b(3), generator)
+        );
+    }
+
+    #[test]
+    fn test_scalar_mul_through_dyn_curve() {
+        let curves: [&dyn Curve; 2] = [&TestCurve, &Secp256k1];
+
+        for curve in curves {
+            let generator = curve.generator_point();
+            let mut expected = CurvePoint::Infinity;
+            for k in 0..8_u32 {
+                assert_eq!(scalar_mul(&b(k), &generator, curve), expected);
+                expected = add_two_points(expected, generator.clone(), curve);
+            }
+
+            let order = curve.order();
+            assert_eq!(
+                scalar_mul(&(&order - 1_u8), &generator, curve),
+                negate_point(generator.clone(), curve)
+            );
+            assert_eq!(scalar_mul(&order, &generator, curve), CurvePoint::Infinity);
+        }
+
+        // The trait method delegates to the free function
+        assert_eq!(
+            scalar_mul(&b(5), &Secp256k1.generator_point(), &Secp256k1),
+            Secp256k1.scalar_mul(&b(5), Secp256k1.generator_point())
+        );
+    }
+
     #[test]
     fn test_point_plus_infinity() {
         let curve = TestCurve;
 
         // Define an affine point P
-        let p = CurvePoint::Affine { x: b(1), y: b(5) };
+        let p = point(5);
 
         // Define the point at infinity
         let inf = CurvePoint::Infinity;
@@ -231,20 +674,22 @@ mod tests {
         // Then P + Q should be O (the point at infinity).
 
         let p = BigUint::from(17u32);
-        let point_x = b(3);
-        let point_y = b(5);
-
-        let p_point = CurvePoint::Affine {
-            x: point_x.clone(),
-            y: point_y.clone(),
+        let p_point = point(6);
+        let CurvePoint::Affine {
+            x: point_x,
+            y: point_y,
+        } = p_point.clone()
+        else {
+            unreachable!("Random points are never the identity");
         };
 
         // The additive inverse of (x, y) is (x, -y mod p)
         let neg_y = (&p - point_y) % &p;
         let minus_p_point = CurvePoint::Affine {
-            x: point_x.clone(),
+            x: point_x,
             y: neg_y,
         };
+        assert!(curve.is_on_curve(&minus_p_point));
 
         let result = add_two_points(p_point.clone(), minus_p_point.clone(), &curve);
         assert_eq!(
@@ -258,15 +703,12 @@ mod tests {
     fn test_regular_addition() {
         let curve = TestCurve;
 
-        // Let's pick two distinct points P and Q on our test curve (assuming they are valid).
+        // Let's pick two distinct points P and Q on our test curve that aren't negatives of each other.
         // We'll check the result is as expected under modulo 17 arithmetic.
-        //
-        // For example, define:
-        //    P = (5, 1)
-        //    Q = (6, 3)
-
-        let p_point = CurvePoint::Affine { x: b(5), y: b(1) };
-        let q_point = CurvePoint::Affine { x: b(6), y: b(3) };
+        let p_point = point(7);
+        let q_point = point(8);
+        assert_ne!(p_point, q_point);
+        assert_ne!(p_point, negate_point(q_point.clone(), &curve));
 
         let result = add_two_points(p_point.clone(), q_point.clone(), &curve);
 
@@ -274,6 +716,30 @@ mod tests {
             result != CurvePoint::Infinity,
             "P + Q should be an affine point for these specific P, Q"
         );
+        assert!(curve.is_on_curve(&result));
+    }
+
+    #[test]
+    fn test_unreduced_inputs() {
+        let curve = TestCurve;
+        let point = point(10);
+        let CurvePoint::Affine { x, y } = point.clone() else {
+            unreachable!("Random points are never the identity");
+        };
+        let unreduced = CurvePoint::Affine {
+            x: x + 17u32,
+            y: y + 2u32 * 17,
+        };
+
+        // Recognized as a doubling rather than a vertical line
+        assert_eq!(
+            add_two_points(unreduced.clone(), point.clone(), &curve),
+            add_two_points(point.clone(), point.clone(), &curve)
+        );
+        assert_eq!(
+            add_two_points(unreduced, CurvePoint::Infinity, &curve),
+            point
+        );
     }
 
     #[test]
@@ -283,7 +749,7 @@ mod tests {
         // Doubling formula test:
         // We pick a point P = (x, y) and compute 2P.
 
-        let p_point = CurvePoint::Affine { x: b(5), y: b(1) };
+        let p_point = point(9);
         let doubled = add_two_points(p_point.clone(), p_point.clone(), &curve);
 
         // We can check the result is not Infinity (unless y=0).
@@ -291,12 +757,14 @@ mod tests {
             doubled != CurvePoint::Infinity,
             "2P should not be Infinity unless y=0"
         );
+        assert!(curve.is_on_curve(&doubled));
     }
 
     #[test]
     fn test_point_doubling_y_zero() {
         let curve = TestCurve;
-        // If y=0, doubling the point results in Infinity.
+        // If y=0, doubling the point results in Infinity. The group has odd order, so no point with y=0 lies
+        // on the test curve and this one is deliberately hand-picked to exercise the formula.
 
         let p_point = CurvePoint::Affine { x: b(5), y: b(0) };
         let doubled = add_two_points(p_point.clone(), p_point.clone(), &curve);
@@ -306,4 +774,101 @@ mod tests {
             "Doubling a point with y=0 should result in Infinity"
         );
     }
+
+    /// Checks on every point of the test curve the invariants asserted by the `add_two_points` fuzz target
+    #[test]
+    fn test_group_law_exhaustively() {
+        let curve = TestCurve;
+        let mut points = vec![CurvePoint::Infinity];
+        for x in 0..17 {
+            for y in 0..17 {
+                let point = CurvePoint::Affine { x: b(x), y: b(y) };
+                if curve.is_on_curve(&point) {
+                    points.push(point);
+                }
+            }
+        }
+        assert_eq!(points.len(), 19);
+
+        for p in &points {
+            for q in &points {
+                let sum = add_two_points(p.clone(), q.clone(), &curve);
+                assert!(
+                    curve.is_on_curve(&sum),
+                    "{} + {} = {} is off the curve",
+                    p,
+                    q,
+                    sum
+                );
+                assert_eq!(sum, add_two_points(q.clone(), p.clone(), &curve));
+
+                for r in &points {
+                    assert_eq!(
+                        add_two_points(sum.clone(), r.clone(), &curve),
+                        add_two_points(
+                            p.clone(),
+                            add_two_points(q.clone(), r.clone(), &curve),
+                            &curve
+                        )
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "property-tests"))]
+mod property_tests {
+    use num_bigint::BigUint;
+    use proptest::prelude::*;
+
+    use super::{add_two_points, negate_point};
+    use crate::elliptic_curves::{
+        curve::{Curve, CurvePoint},
+        generic::GenericCurve,
+    };
+
+    fn curve() -> GenericCurve {
+        GenericCurve::toy()
+    }
+
+    /// Any point of the group, as a multiple of the generator (0 gives the point at infinity)
+    fn point() -> impl Strategy<Value = CurvePoint> {
+        (0..19_u8).prop_map(|k| curve().scalar_mul(&BigUint::from(k), curve().generator_point()))
+    }
+
+    proptest! {
+        #[test]
+        fn test_addition_is_commutative(p in point(), q in point()) {
+            let curve = curve();
+            prop_assert_eq!(
+                add_two_points(p.clone(), q.clone(), &curve),
+                add_two_points(q, p, &curve)
+            );
+        }
+
+        #[test]
+        fn test_addition_is_associative(p in point(), q in point(), r in point()) {
+            let curve = curve();
+            prop_assert_eq!(
+                add_two_points(add_two_points(p.clone(), q.clone(), &curve), r.clone(), &curve),
+                add_two_points(p, add_two_points(q, r, &curve), &curve)
+            );
+        }
+
+        #[test]
+        fn test_infinity_is_the_identity(p in point()) {
+            let curve = curve();
+            prop_assert_eq!(add_two_points(p.clone(), curve.identity(), &curve), p.clone());
+            prop_assert_eq!(add_two_points(curve.identity(), p.clone(), &curve), p);
+        }
+
+        #[test]
+        fn test_negation_is_the_inverse(p in point()) {
+            let curve = curve();
+            let negated = negate_point(p.clone(), &curve);
+            prop_assert!(curve.is_on_curve(&negated));
+            prop_assert_eq!(add_two_points(p, negated, &curve), curve.identity());
+        }
+    }
 }
@@ -0,0 +1,118 @@
+use alloc::boxed::Box;
+
+use num_bigint::BigUint;
+use once_cell::race::OnceBox;
+
+use super::curve::{Curve, CurvePoint};
+
+/// Big-endian curve constants from RFC 5639, section 3.4
+const GENERATOR_X_BYTES: [u8; 32] = [
+    0x8b, 0xd2, 0xae, 0xb9, 0xcb, 0x7e, 0x57, 0xcb, 0x2c, 0x4b, 0x48, 0x2f, 0xfc, 0x81, 0xb7, 0xaf,
+    0xb9, 0xde, 0x27, 0xe1, 0xe3, 0xbd, 0x23, 0xc2, 0x3a, 0x44, 0x53, 0xbd, 0x9a, 0xce, 0x32, 0x62,
+];
+const GENERATOR_Y_BYTES: [u8; 32] = [
+    0x54, 0x7e, 0xf8, 0x35, 0xc3, 0xda, 0xc4, 0xfd, 0x97, 0xf8, 0x46, 0x1a, 0x14, 0x61, 0x1d, 0xc9,
+    0xc2, 0x77, 0x45, 0x13, 0x2d, 0xed, 0x8e, 0x54, 0x5c, 0x1d, 0x54, 0xc7, 0x2f, 0x04, 0x69, 0x97,
+];
+const PRIME_MODULUS_BYTES: [u8; 32] = [
+    0xa9, 0xfb, 0x57, 0xdb, 0xa1, 0xee, 0xa9, 0xbc, 0x3e, 0x66, 0x0a, 0x90, 0x9d, 0x83, 0x8d, 0x72,
+    0x6e, 0x3b, 0xf6, 0x23, 0xd5, 0x26, 0x20, 0x28, 0x20, 0x13, 0x48, 0x1d, 0x1f, 0x6e, 0x53, 0x77,
+];
+const A_BYTES: [u8; 32] = [
+    0x7d, 0x5a, 0x09, 0x75, 0xfc, 0x2c, 0x30, 0x57, 0xee, 0xf6, 0x75, 0x30, 0x41, 0x7a, 0xff, 0xe7,
+    0xfb, 0x80, 0x55, 0xc1, 0x26, 0xdc, 0x5c, 0x6c, 0xe9, 0x4a, 0x4b, 0x44, 0xf3, 0x30, 0xb5, 0xd9,
+];
+const B_BYTES: [u8; 32] = [
+    0x26, 0xdc, 0x5c, 0x6c, 0xe9, 0x4a, 0x4b, 0x44, 0xf3, 0x30, 0xb5, 0xd9, 0xbb, 0xd7, 0x7c, 0xbf,
+    0x95, 0x84, 0x16, 0x29, 0x5c, 0xf7, 0xe1, 0xce, 0x6b, 0xcc, 0xdc, 0x18, 0xff, 0x8c, 0x07, 0xb6,
+];
+const ORDER_BYTES: [u8; 32] = [
+    0xa9, 0xfb, 0x57, 0xdb, 0xa1, 0xee, 0xa9, 0xbc, 0x3e, 0x66, 0x0a, 0x90, 0x9d, 0x83, 0x8d, 0x71,
+    0x8c, 0x39, 0x7a, 0xa3, 0xb5, 0x61, 0xa6, 0xf7, 0x90, 0x1e, 0x0e, 0x82, 0x97, 0x48, 0x56, 0xa7,
+];
+
+/// Curve constants, built from the bytes on first use
+static GENERATOR: OnceBox<CurvePoint> = OnceBox::new();
+static PRIME_MODULUS: OnceBox<BigUint> = OnceBox::new();
+static A: OnceBox<BigUint> = OnceBox::new();
+static B: OnceBox<BigUint> = OnceBox::new();
+static ORDER: OnceBox<BigUint> = OnceBox::new();
+
+/// Implementation of the Brainpool P-256 r1 elliptic curve (RFC 5639, section 3.4)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BrainpoolP256r1;
+
+impl Curve for BrainpoolP256r1 {
+    fn generator_point(&self) -> CurvePoint {
+        GENERATOR
+            .get_or_init(|| {
+                Box::new(CurvePoint::Affine {
+                    x: BigUint::from_bytes_be(&GENERATOR_X_BYTES),
+                    y: BigUint::from_bytes_be(&GENERATOR_Y_BYTES),
+                })
+            })
+            .clone()
+    }
+
+    fn prime_modulus(&self) -> BigUint {
+        PRIME_MODULUS
+            .get_or_init(|| Box::new(BigUint::from_bytes_be(&PRIME_MODULUS_BYTES)))
+            .clone()
+    }
+
+    fn a(&self) -> BigUint {
+        A.get_or_init(|| Box::new(BigUint::from_bytes_be(&A_BYTES)))
+            .clone()
+    }
+
+    fn b(&self) -> BigUint {
+        B.get_or_init(|| Box::new(BigUint::from_bytes_be(&B_BYTES)))
+            .clone()
+    }
+
+    fn order(&self) -> BigUint {
+        ORDER
+            .get_or_init(|| Box::new(BigUint::from_bytes_be(&ORDER_BYTES)))
+            .clone()
+    }
+
+    fn identity(&self) -> CurvePoint {
+        CurvePoint::Infinity
+    }
+
+    /// 1.3.36.3.3.2.8.1.1.7
+    fn oid(&self) -> Option<&'static [u8]> {
+        Some(&[0x2b, 0x24, 0x03, 0x03, 0x02, 0x08, 0x01, 0x01, 0x07])
+    }
+
+    fn name(&self) -> &'static str {
+        "brainpoolP256r1"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::{BrainpoolP256r1, Curve};
+
+    #[test]
+    fn should_have_valid_parameters() {
+        assert_eq!(BrainpoolP256r1.validate(), Ok(()));
+    }
+
+    #[test]
+    fn generator_should_be_on_the_curve_and_have_the_stated_order() {
+        let curve = BrainpoolP256r1;
+        let generator = curve.generator_point();
+
+        assert!(curve.is_on_curve(&generator));
+        assert!(curve.scalar_mul(&curve.order(), generator).is_infinity());
+        assert_eq!(curve.cofactor(), BigUint::from(1_u8));
+    }
+
+    #[test]
+    fn should_provide_128_bit_security() {
+        assert_eq!(BrainpoolP256r1.security_bits(), 128);
+    }
+}
@@ -0,0 +1,293 @@
+use alloc::{vec, vec::Vec};
+
+use num_bigint::BigUint;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::curve::CurvePoint;
+
+/// Compares two integers for equality without branching on their values.
+///
+/// `BigUint`'s `==` returns as soon as a limb differs, which leaks timing information when one of
+/// the operands is secret. Here both values are serialized to little-endian bytes padded to a common
+/// width and compared with `subtle`, so only that width (the size of the larger operand) is revealed.
+///
+/// Used for the secret-dependent checks in the signing path:
+/// - `schnorr_sign_with_aux`: the secret key being zero and the derived nonce being zero.
+/// - `sign_with_nonce` (ECDSA): `r` being zero and `s` being zero.
+pub fn ct_eq(a: &BigUint, b: &BigUint) -> bool {
+    let mut a_bytes = a.to_bytes_le();
+    let mut b_bytes = b.to_bytes_le();
+    let width = a_bytes.len().max(b_bytes.len());
+    a_bytes.resize(width, 0);
+    b_bytes.resize(width, 0);
+
+    bool::from(a_bytes.ct_eq(&b_bytes))
+}
+
+/// Compares two points for equality without branching on their coordinates.
+///
+/// The points are serialized like in [`cswap`] and compared with `subtle`, so only the width of the larger
+/// coordinate is revealed. Like `==`, the coordinates are compared as given, without reducing them. Meant for
+/// checks where a point derived from secret data is matched against an expected one.
+pub fn ct_point_eq(a: &CurvePoint, b: &CurvePoint) -> bool {
+    let width = coordinate_width(a).max(coordinate_width(b));
+    bool::from(to_swap_bytes(a, width).ct_eq(&to_swap_bytes(b, width)))
+}
+
+/// Computes the inverse of a secret `value` modulo `prime_modulus` as \( a^{p-2} \mod p \) (Fermat's little
+/// theorem).
+///
+/// The exponent \( p - 2 \) is public, so the square-and-multiply sequence doesn't depend on `value`, unlike
+/// the branches and iteration count of an extended Euclidean inverse. As with the other helpers here, the
+/// `BigUint` limb arithmetic underneath is still variable-time.
+///
+/// Which inverse to use:
+/// - `ct_mod_inv` for secret values, e.g. the nonce inverse \( k^{-1} \) in `sign_with_nonce` (ECDSA).
+/// - `try_mod_inv` (or `inv_mod_order`) for public values, e.g. \( s^{-1} \) in `verify_prehashed`, where
+///   rejecting zero with an error is more useful than hiding it.
+///
+/// # Returns
+/// - The inverse of `value`, or zero if `value` is a multiple of `prime_modulus`. Checking for that case is left
+///   to the caller, which can do it with [`ct_eq`].
+pub fn ct_mod_inv(value: &BigUint, prime_modulus: &BigUint) -> BigUint {
+    (value % prime_modulus).modpow(&(prime_modulus - 2_u8), prime_modulus)
+}
+
+/// Swaps `a` and `b` if `swap` is set, without branching on `swap`.
+///
+/// Both points are serialized to a flag byte for the point at infinity followed by their little-endian
+/// coordinates padded to a common width, and the bytes are swapped with `subtle`'s masked selection. As with
+/// [`ct_eq`], only that width is revealed, not whether the points were swapped.
+///
+/// A building block for constant-time ladders, not a complete side-channel defense: the `BigUint` arithmetic
+/// around it is still variable-time.
+pub fn cswap(swap: bool, a: &mut CurvePoint, b: &mut CurvePoint) {
+    let width = coordinate_width(a).max(coordinate_width(b));
+    let mut a_bytes = to_swap_bytes(a, width);
+    let mut b_bytes = to_swap_bytes(b, width);
+
+    let choice = Choice::from(u8::from(swap));
+    for (a_byte, b_byte) in a_bytes.iter_mut().zip(b_bytes.iter_mut()) {
+        u8::conditional_swap(a_byte, b_byte, choice);
+    }
+
+    *a = from_swap_bytes(&a_bytes, width);
+    *b = from_swap_bytes(&b_bytes, width);
+}
+
+/// Swaps the integers `a` and `b` if `swap` is set, without branching on `swap`.
+///
+/// Both are serialized to exactly `width` little-endian bytes, which the caller fixes in advance (e.g. the
+/// byte length of the field prime) so that not even the size of the operands is revealed. This is the
+/// `cswap` of the Montgomery ladder in RFC 7748, section 5.
+///
+/// Both values must fit in `width` bytes.
+pub fn cswap_uint(swap: bool, a: &mut BigUint, b: &mut BigUint, width: usize) {
+    debug_assert!(
+        a.bits().max(b.bits()) <= 8 * width as u64,
+        "value wider than width"
+    );
+    let mut a_bytes = a.to_bytes_le();
+    let mut b_bytes = b.to_bytes_le();
+    a_bytes.resize(width, 0);
+    b_bytes.resize(width, 0);
+
+    let choice = Choice::from(u8::from(swap));
+    for (a_byte, b_byte) in a_bytes.iter_mut().zip(b_bytes.iter_mut()) {
+        u8::conditional_swap(a_byte, b_byte, choice);
+    }
+
+    *a = BigUint::from_bytes_le(&a_bytes);
+    *b = BigUint::from_bytes_le(&b_bytes);
+}
+
+/// Returns `table[index]` without the memory access pattern depending on `index`.
+///
+/// Every entry is serialized like in [`cswap`] and conditionally copied into the result with `subtle`, so all of
+/// them are read whichever one is selected. Meant for tables indexed by secret scalar digits, such as the
+/// lookups in `GeneratorComb::mul`.
+///
+/// `index` must be below `table.len()`.
+pub fn ct_select(table: &[CurvePoint], index: usize) -> CurvePoint {
+    debug_assert!(index < table.len(), "index out of bounds");
+    let width = table.iter().map(coordinate_width).max().unwrap_or(0);
+
+    let mut selected = vec![0_u8; 1 + 2 * width];
+    for (i, entry) in table.iter().enumerate() {
+        let choice = (i as u64).ct_eq(&(index as u64));
+        for (byte, entry_byte) in selected.iter_mut().zip(to_swap_bytes(entry, width)) {
+            byte.conditional_assign(&entry_byte, choice);
+        }
+    }
+
+    from_swap_bytes(&selected, width)
+}
+
+fn coordinate_width(point: &CurvePoint) -> usize {
+    match point {
+        CurvePoint::Affine { x, y } => x.bits().max(y.bits()).div_ceil(8) as usize,
+        CurvePoint::Infinity => 0,
+    }
+}
+
+/// Lays a point out as `infinity flag || x || y`, with the coordinates of the point at infinity all zero
+fn to_swap_bytes(point: &CurvePoint, width: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(1 + 2 * width);
+    match point {
+        CurvePoint::Affine { x, y } => {
+            bytes.push(0);
+            for coordinate in [x, y] {
+                let mut coordinate = coordinate.to_bytes_le();
+                coordinate.resize(width, 0);
+                bytes.extend_from_slice(&coordinate);
+            }
+        }
+        CurvePoint::Infinity => bytes.resize(1 + 2 * width, 0xff),
+    }
+    bytes
+}
+
+fn from_swap_bytes(bytes: &[u8], width: usize) -> CurvePoint {
+    if bytes[0] != 0 {
+        return CurvePoint::Infinity;
+    }
+    let (x, y) = bytes[1..].split_at(width);
+    CurvePoint::Affine {
+        x: BigUint::from_bytes_le(x),
+        y: BigUint::from_bytes_le(y),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::{cswap, cswap_uint, ct_eq, ct_mod_inv, ct_point_eq, ct_select};
+    use crate::elliptic_curves::{
+        arithmetic::try_mod_inv,
+        curve::{Curve, CurvePoint},
+        secp256k1::Secp256k1,
+    };
+
+    #[test]
+    fn test_ct_eq_matches_eq() {
+        let values = [
+            BigUint::ZERO,
+            BigUint::from(1_u8),
+            BigUint::from(255_u8),
+            BigUint::from(256_u16),
+            BigUint::from(u64::MAX),
+            BigUint::from(u64::MAX) + 1_u8,
+            BigUint::from(1_u8) << 255,
+            (BigUint::from(1_u8) << 256) - 1_u8,
+        ];
+
+        for a in &values {
+            for b in &values {
+                assert_eq!(ct_eq(a, b), a == b, "a = {}, b = {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ct_point_eq_matches_eq() {
+        let curve = Secp256k1;
+        let mut points = vec![CurvePoint::Infinity];
+        points.extend((1..4_u8).map(|k| curve.calculate_public_key(BigUint::from(k))));
+        // Short coordinates, and the same x with a different y
+        points.extend(
+            [(5_u8, 0_u8), (5, 1), (0, 0)].map(|(x, y)| CurvePoint::Affine {
+                x: BigUint::from(x),
+                y: BigUint::from(y),
+            }),
+        );
+
+        for a in &points {
+            for b in &points {
+                assert_eq!(ct_point_eq(a, b), a == b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ct_mod_inv_matches_try_mod_inv() {
+        let p = BigUint::from(19_u8);
+        for value in 1..19_u8 {
+            let value = BigUint::from(value);
+            assert_eq!(Ok(ct_mod_inv(&value, &p)), try_mod_inv(&value, &p));
+        }
+
+        let n = Secp256k1.order();
+        for value in [
+            BigUint::from(1_u8),
+            BigUint::from(0xdead_beef_u32),
+            &n - 1_u8,
+            &n + 2_u8,
+        ] {
+            let inverse = ct_mod_inv(&value, &n);
+            assert_eq!(Ok(inverse.clone()), try_mod_inv(&value, &n));
+            assert_eq!(value * inverse % &n, BigUint::from(1_u8));
+        }
+
+        // Zero has no inverse and maps to zero
+        assert_eq!(ct_mod_inv(&BigUint::ZERO, &n), BigUint::ZERO);
+        assert_eq!(ct_mod_inv(&n, &n), BigUint::ZERO);
+    }
+
+    #[test]
+    fn test_cswap() {
+        let points = [
+            CurvePoint::Affine {
+                x: BigUint::from(5_u8),
+                y: BigUint::from(1_u8),
+            },
+            CurvePoint::Affine {
+                x: BigUint::from(1_u8) << 255,
+                y: BigUint::ZERO,
+            },
+            CurvePoint::Infinity,
+        ];
+
+        for first in &points {
+            for second in &points {
+                let (mut a, mut b) = (first.clone(), second.clone());
+                cswap(false, &mut a, &mut b);
+                assert_eq!((&a, &b), (first, second));
+
+                cswap(true, &mut a, &mut b);
+                assert_eq!((&a, &b), (second, first));
+            }
+        }
+    }
+
+    #[test]
+    fn test_ct_select_matches_indexing() {
+        let curve = Secp256k1;
+        let mut table = vec![CurvePoint::Infinity];
+        table.extend((1..8_u8).map(|k| curve.calculate_public_key(BigUint::from(k))));
+        // A short coordinate next to the 256-bit ones
+        table.push(CurvePoint::Affine {
+            x: BigUint::from(5_u8),
+            y: BigUint::ZERO,
+        });
+
+        for (index, entry) in table.iter().enumerate() {
+            assert_eq!(&ct_select(&table, index), entry);
+        }
+    }
+
+    #[test]
+    fn test_cswap_uint() {
+        let (small, large) = (
+            BigUint::from(7_u8),
+            (BigUint::from(1_u8) << 255_u32) - 19_u8,
+        );
+
+        let (mut a, mut b) = (small.clone(), large.clone());
+        cswap_uint(false, &mut a, &mut b, 32);
+        assert_eq!((&a, &b), (&small, &large));
+
+        cswap_uint(true, &mut a, &mut b, 32);
+        assert_eq!((a, b), (large, small));
+    }
+}
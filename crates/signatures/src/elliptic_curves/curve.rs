@@ -1,22 +1,113 @@
+use alloc::vec::Vec;
+
 use num_bigint::{BigUint, RandBigInt};
+#[cfg(feature = "std")]
 use rand::thread_rng;
+use rand::RngCore;
+use sha2::{Digest, Sha256, Sha512};
 
-use super::arithmetic::add_two_points;
+use super::{
+    arithmetic::{mod_add, mod_inv, mod_mul, mod_sqrt, mod_sub, scalar_mul},
+    sec1::from_sec1,
+};
+use crate::error::Error;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CurvePoint {
     Affine { x: BigUint, y: BigUint },
     Infinity,
 }
 
 impl CurvePoint {
+    /// Creates an affine point of `curve`, reducing the coordinates modulo \( p \) first.
+    ///
+    /// Prefer this over building `CurvePoint::Affine` directly from untrusted coordinates.
+    ///
+    /// # Errors
+    /// - `Error::PointNotOnCurve` if the reduced coordinates don't satisfy the curve equation.
+    pub fn new_affine<T: Curve>(x: BigUint, y: BigUint, curve: &T) -> Result<Self, Error> {
+        let p = curve.prime_modulus();
+        let point = CurvePoint::Affine {
+            x: x % &p,
+            y: y % &p,
+        };
+        if !curve.is_on_curve(&point) {
+            return Err(Error::PointNotOnCurve);
+        }
+        Ok(point)
+    }
+
     /// Check if the point is the point at infinity
     pub fn is_infinity(&self) -> bool {
         matches!(self, CurvePoint::Infinity)
     }
 }
 
-/// Trait representing an elliptic curve
+/// Orders the point at infinity first and affine points lexicographically by `(x, y)`
+impl Ord for CurvePoint {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match (self, other) {
+            (CurvePoint::Infinity, CurvePoint::Infinity) => core::cmp::Ordering::Equal,
+            (CurvePoint::Infinity, CurvePoint::Affine { .. }) => core::cmp::Ordering::Less,
+            (CurvePoint::Affine { .. }, CurvePoint::Infinity) => core::cmp::Ordering::Greater,
+            (CurvePoint::Affine { x: x1, y: y1 }, CurvePoint::Affine { x: x2, y: y2 }) => {
+                x1.cmp(x2).then_with(|| y1.cmp(y2))
+            }
+        }
+    }
+}
+
+impl PartialOrd for CurvePoint {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Formats the point as `Infinity` or `(x, y)` with lowercase hex coordinates
+impl core::fmt::Display for CurvePoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CurvePoint::Affine { x, y } => write!(f, "({:x}, {:x})", x, y),
+            CurvePoint::Infinity => write!(f, "Infinity"),
+        }
+    }
+}
+
+/// Parses the format produced by `Display`
+impl core::str::FromStr for CurvePoint {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "Infinity" {
+            return Ok(CurvePoint::Infinity);
+        }
+
+        let (x, y) = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .and_then(|s| s.split_once(", "))
+            .ok_or(Error::MalformedPoint)?;
+
+        let parse_coordinate = |hex: &str| {
+            if hex.is_empty() || !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+                return Err(Error::MalformedPoint);
+            }
+            BigUint::parse_bytes(hex.as_bytes(), 16).ok_or(Error::MalformedPoint)
+        };
+
+        Ok(CurvePoint::Affine {
+            x: parse_coordinate(x)?,
+            y: parse_coordinate(y)?,
+        })
+    }
+}
+
+/// Trait representing an elliptic curve.
+///
+/// The trait is dyn-compatible, so curves of different types can be stored together, e.g. as
+/// `Vec<Box<dyn Curve>>`. Methods that take a generic parameter or hand `self` to the generic arithmetic
+/// (`scalar_mul`, `calculate_public_key`, `validate`, ...) require `Self: Sized` and are only available on
+/// concrete curve types. Trait objects can use `arithmetic::scalar_mul` instead.
 pub trait Curve {
     /// Returns the generator point of the curve
     fn generator_point(&self) -> CurvePoint;
@@ -31,20 +122,392 @@ pub trait Curve {
     /// Returns the identity point (point at infinity)
     fn identity(&self) -> CurvePoint;
 
-    /// Generate a random secret key
+    /// Returns the cofactor \( h \), the number of curve points divided by the order of the generator
+    fn cofactor(&self) -> BigUint {
+        BigUint::from(1_u8)
+    }
+
+    /// Returns the content bytes of the DER encoded object identifier naming the curve (RFC 5480), if it has one.
+    ///
+    /// Key formats such as SEC1's `ECPrivateKey` refer to the curve by this identifier.
+    fn oid(&self) -> Option<&'static [u8]> {
+        None
+    }
+
+    /// Returns a stable, human-readable identifier for the curve, such as `"secp256k1"` or `"P-256"`.
+    ///
+    /// Named curves use the spelling of the JOSE `crv` parameter (RFC 7518, RFC 8812) where one exists;
+    /// curves without a standard name are `"custom"`.
+    fn name(&self) -> &'static str {
+        "custom"
+    }
+
+    /// Returns \( \lfloor n / 2 \rfloor \), the bound for low-S ECDSA signatures.
+    ///
+    /// Curves with fixed parameters may cache it, as it is compared against for every signature.
+    fn half_order(&self) -> BigUint {
+        self.order() >> 1
+    }
+
+    /// Returns the number of bytes needed to encode a field element (a coordinate)
+    fn field_byte_size(&self) -> usize {
+        self.prime_modulus().bits().div_ceil(8) as usize
+    }
+
+    /// Returns the bit length of the order \( n \), to which ECDSA truncates message digests
+    fn order_bits(&self) -> u64 {
+        self.order().bits()
+    }
+
+    /// Returns the number of bytes needed to encode a scalar (e.g. a secret key or a signature component)
+    fn order_byte_size(&self) -> usize {
+        self.order_bits().div_ceil(8) as usize
+    }
+
+    /// Returns the approximate security level in bits, half the bit length of the order.
+    ///
+    /// The best known generic attack on the discrete logarithm (Pollard's rho) takes about \( \sqrt{n} \) steps.
+    fn security_bits(&self) -> u32 {
+        (self.order_bits() / 2) as u32
+    }
+
+    /// Generate a random secret key using `thread_rng`.
+    ///
+    /// Tests that need reproducible keys can pass a seeded RNG to `generate_secret_key_with_rng` instead.
+    #[cfg(feature = "std")]
     fn generate_secret_key(&self) -> BigUint {
-        let mut rng = thread_rng();
+        random_secret(&self.order(), &mut thread_rng())
+    }
+
+    /// Generate a random secret key in `[1, order)` using the given random number generator
+    fn generate_secret_key_with_rng<R: RngCore + ?Sized>(&self, rng: &mut R) -> BigUint
+    where
+        Self: Sized,
+    {
+        random_secret(&self.order(), rng)
+    }
+
+    /// Derives a secret key in `[1, order)` from `index`, giving tests many distinct but reproducible keys
+    /// without sharing an RNG.
+    ///
+    /// The key is SHA-512 of a domain tag, the big-endian `index` and a counter, reduced modulo the order. The
+    /// counter only moves on if that yields zero. Anyone who knows the index knows the key, so like
+    /// `random_point` this is only built for the crate's own tests and with the `testing` feature.
+    #[cfg(any(test, feature = "testing"))]
+    fn generate_secret_key_deterministic(&self, index: u64) -> BigUint {
+        hash_to_secret(
+            self,
+            &[b"shai/deterministic-secret-key", &index.to_be_bytes()],
+        )
+    }
+
+    /// Doubles a point with the tangent formula, skipping the case analysis of `add_two_points`.
+    ///
+    /// - \( \lambda = \frac{3x^2 + a}{2y} \)
+    /// - \( x' = \lambda^2 - 2x \)
+    /// - \( y' = \lambda(x - x') - y \)
+    ///
+    /// The point at infinity and points with \( y = 0 \) (of order two) double to the point at infinity.
+    fn double(&self, point: CurvePoint) -> CurvePoint {
+        let CurvePoint::Affine { x, y } = point else {
+            return CurvePoint::Infinity;
+        };
+        if y == BigUint::ZERO {
+            return CurvePoint::Infinity;
+        }
+        let p = self.prime_modulus();
+
+        let numerator = mod_add(&(BigUint::from(3_u8) * mod_mul(&x, &x, &p)), &self.a(), &p);
+        let denominator = mod_add(&y, &y, &p);
+        let lambda = mod_mul(&numerator, &mod_inv(denominator, &p), &p);
+
+        let x3 = mod_sub(&mod_mul(&lambda, &lambda, &p), &mod_add(&x, &x, &p), &p);
+        let y3 = mod_sub(&mod_mul(&lambda, &mod_sub(&x, &x3, &p), &p), &y, &p);
+        CurvePoint::Affine { x: x3, y: y3 }
+    }
+
+    /// Checks whether `s` is a usable non-zero scalar, i.e. \( 0 < s < n \).
+    ///
+    /// This is the range required of secret keys, nonces and ECDSA signature components.
+    fn is_valid_scalar(&self, s: &BigUint) -> bool {
+        *s != BigUint::ZERO && *s < self.order()
+    }
+
+    /// Reduces `value` modulo the order \( n \), e.g. a message hash or an intermediate of the signature math
+    fn reduce_scalar(&self, value: &BigUint) -> BigUint {
+        value % self.order()
+    }
+
+    /// Returns \( -s \mod n \) in `[0, n)`, so that \( sP + (-s)P = O \), e.g. to flip a secret or a nonce to the
+    /// even-y point in BIP340.
+    fn negate_scalar(&self, s: &BigUint) -> BigUint {
         let order = self.order();
-        rng.gen_biguint_range(&BigUint::from(1_u8), &order)
+        (&order - s % &order) % &order
     }
 
-    /// Calculates the public key by scalar multiplication of the secret key with the generator point.
+    /// Reduces the coordinates of an affine point modulo \( p \), leaving the point at infinity unchanged.
     ///
-    /// Uses the double-and-add method to perform scalar multiplication:
-    /// 1. Start with the identity point as the result.
-    /// 2. For each bit of the secret key:
-    ///    - If the bit is set, add the current generator multiple to the result.
-    ///    - Double the current generator multiple.
+    /// `CurvePoint::Affine` can be built with coordinates \( \geq p \); this maps them to the canonical
+    /// representation the rest of the crate compares against.
+    fn normalize(&self, point: CurvePoint) -> CurvePoint {
+        match point {
+            CurvePoint::Affine { x, y } => {
+                let p = self.prime_modulus();
+                CurvePoint::Affine {
+                    x: x % &p,
+                    y: y % &p,
+                }
+            }
+            CurvePoint::Infinity => CurvePoint::Infinity,
+        }
+    }
+
+    /// Checks whether a point satisfies the curve equation \( y^2 = x^3 + ax + b \mod p \).
+    ///
+    /// The point at infinity is the group identity and is always considered on the curve.
+    fn is_on_curve(&self, point: &CurvePoint) -> bool {
+        let CurvePoint::Affine { x, y } = point else {
+            return true;
+        };
+        let p = self.prime_modulus();
+        if x >= &p || y >= &p {
+            return false;
+        }
+
+        let lhs = y.modpow(&BigUint::from(2_u8), &p);
+        let rhs = (x.modpow(&BigUint::from(3_u8), &p) + self.a() * x + self.b()) % &p;
+        lhs == rhs
+    }
+
+    /// Checks whether \( a = -b \), i.e. both points share their x-coordinate and their y-coordinates sum to
+    /// \( 0 \mod p \).
+    ///
+    /// The point at infinity is its own negative, and so is a point with \( y = 0 \).
+    fn is_negative_of(&self, a: &CurvePoint, b: &CurvePoint) -> bool {
+        match (a, b) {
+            (CurvePoint::Infinity, CurvePoint::Infinity) => true,
+            (CurvePoint::Affine { x: x1, y: y1 }, CurvePoint::Affine { x: x2, y: y2 }) => {
+                let p = self.prime_modulus();
+                x1 % &p == x2 % &p && (y1 + y2) % &p == BigUint::ZERO
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns both points with x-coordinate `x`, the one with an even y-coordinate first.
+    ///
+    /// The two candidates \( (x, y) \) and \( (x, p - y) \) are negatives of each other. When \( y = 0 \) they
+    /// coincide and the same point is returned twice.
+    ///
+    /// # Returns
+    /// - `None` if \( x \geq p \) or \( x^3 + ax + b \) is not a square, i.e. no point has this x-coordinate.
+    fn decompress_candidates(&self, x: &BigUint) -> Option<(CurvePoint, CurvePoint)> {
+        let p = self.prime_modulus();
+        if *x >= p {
+            return None;
+        }
+
+        let rhs = (x.modpow(&BigUint::from(3_u8), &p) + self.a() * x + self.b()) % &p;
+        let y = mod_sqrt(&rhs, &p)?;
+        let negated = mod_sub(&BigUint::ZERO, &y, &p);
+        let (even, odd) = if y.bit(0) { (negated, y) } else { (y, negated) };
+
+        Some((
+            CurvePoint::Affine {
+                x: x.clone(),
+                y: even,
+            },
+            CurvePoint::Affine {
+                x: x.clone(),
+                y: odd,
+            },
+        ))
+    }
+
+    /// Returns the point with x-coordinate `x` whose y-coordinate has the requested parity.
+    ///
+    /// BIP340 Schnorr keys and nonces are x-only and always lift to the even candidate. When \( y = 0 \) there is
+    /// no odd candidate, and that (even) point is returned for either parity, as in `decompress_candidates`.
+    ///
+    /// # Returns
+    /// - `None` if no point has this x-coordinate.
+    fn lift_x(&self, x: &BigUint, even_y: bool) -> Option<CurvePoint> {
+        let (even, odd) = self.decompress_candidates(x)?;
+        Some(if even_y { even } else { odd })
+    }
+
+    /// Lists every point of the curve, the point at infinity first and then the affine points by ascending x.
+    ///
+    /// Meant for tests and experiments on toy curves: the work is linear in \( p \).
+    ///
+    /// # Panics
+    /// - If the prime modulus is wider than 16 bits, to catch accidental calls on real curves.
+    fn enumerate_points(&self) -> Vec<CurvePoint> {
+        let p = self.prime_modulus();
+        assert!(
+            p.bits() <= 16,
+            "enumerate_points is only meant for curves over fields of at most 16 bits, not {}",
+            self.name()
+        );
+
+        let mut points = Vec::from([CurvePoint::Infinity]);
+        let mut x = BigUint::ZERO;
+        while x < p {
+            if let Some((even, odd)) = self.decompress_candidates(&x) {
+                // A zero y-coordinate gives a single point
+                let single = even == odd;
+                points.push(even);
+                if !single {
+                    points.push(odd);
+                }
+            }
+            x += 1_u8;
+        }
+        points
+    }
+
+    /// Decodes a SEC1 encoded point (compressed, uncompressed or the point at infinity) of this curve.
+    ///
+    /// `CurvePoint` doesn't know its curve, so this stands in for a `TryFrom<&[u8]>` implementation.
+    ///
+    /// # Errors
+    /// - Any error raised by `from_sec1`.
+    fn point_from_bytes(&self, bytes: &[u8]) -> Result<CurvePoint, Error>
+    where
+        Self: Sized,
+    {
+        from_sec1(bytes, self)
+    }
+
+    /// Returns the discriminant \( \Delta = -16(4a^3 + 27b^2) \mod p \).
+    ///
+    /// The curve is non-singular (has no cusps or self-intersections, so its points form a group) iff
+    /// \( \Delta \neq 0 \).
+    fn discriminant(&self) -> BigUint {
+        let p = self.prime_modulus();
+        let sum = BigUint::from(4_u8) * self.a().modpow(&BigUint::from(3_u8), &p)
+            + BigUint::from(27_u8) * self.b().modpow(&BigUint::from(2_u8), &p);
+        mod_sub(&BigUint::ZERO, &(BigUint::from(16_u8) * sum), &p)
+    }
+
+    /// Checks that the curve parameters describe a usable group.
+    ///
+    /// The following properties are verified:
+    /// - The curve is non-singular: its [`Curve::discriminant`] is non-zero.
+    /// - The generator is not the point at infinity, which generates nothing but itself.
+    /// - The generator point satisfies the curve equation.
+    /// - The number of points \( nh \) implied by the order and cofactor is within the Hasse bound
+    ///   \( |p + 1 - nh| \le 2\sqrt{p} \), which rules out e.g. a multiple of the actual order.
+    /// - The generator has the declared order: \( n \cdot G = O \).
+    fn validate(&self) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        if self.discriminant() == BigUint::ZERO {
+            return Err(Error::SingularCurve);
+        }
+
+        let generator = self.generator_point();
+        if generator.is_infinity() {
+            return Err(Error::GeneratorIsIdentity);
+        }
+        if !self.is_on_curve(&generator) {
+            return Err(Error::GeneratorNotOnCurve);
+        }
+
+        // (p + 1 - nh)^2 <= 4p avoids the square root
+        let p = self.prime_modulus();
+        let points = self.order() * self.cofactor();
+        let p_plus_one = &p + 1_u8;
+        let distance = if points > p_plus_one {
+            points - p_plus_one
+        } else {
+            p_plus_one - points
+        };
+        if &distance * &distance > p * 4_u8 {
+            return Err(Error::OrderOutsideHasseBound);
+        }
+
+        if !self.scalar_mul(&self.order(), generator).is_infinity() {
+            return Err(Error::InvalidGeneratorOrder);
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether a point lies in the prime-order subgroup generated by the generator point.
+    ///
+    /// A point is accepted iff it is on the curve and \( n \cdot P = O \). On curves with a cofactor
+    /// this rejects small-order (torsion) points that would otherwise enable small-subgroup attacks.
+    fn is_in_subgroup(&self, point: &CurvePoint) -> bool
+    where
+        Self: Sized,
+    {
+        self.is_on_curve(point) && self.scalar_mul(&self.order(), point.clone()).is_infinity()
+    }
+
+    /// Multiplies a point by the cofactor \( h \), which maps every curve point into the prime-order subgroup.
+    ///
+    /// The small-order (torsion) component of the point is killed in the process, so an untrusted point can be
+    /// cleared before e.g. ECDH instead of being rejected. Points of the subgroup are multiplied by \( h \) too,
+    /// which protocols on cofactor curves account for. With the default cofactor of 1 the point is returned
+    /// unchanged.
+    fn clear_cofactor(&self, point: CurvePoint) -> CurvePoint
+    where
+        Self: Sized,
+    {
+        self.scalar_mul(&self.cofactor(), point)
+    }
+
+    /// Deterministically maps arbitrary bytes to a point on the curve.
+    ///
+    /// Uses the try-and-increment method:
+    /// 1. Hash a 32-bit counter together with `data` (repeating with a block index until the digest
+    ///    is at least as wide as the prime modulus) and reduce it modulo \( p \) to get a candidate \( x \).
+    /// 2. If \( x^3 + ax + b \) is a quadratic residue, return \( (x, y) \) with the even root \( y \).
+    /// 3. Otherwise increment the counter and try again.
+    ///
+    /// Roughly half of all candidates succeed, so only a couple of attempts are expected.
+    fn hash_to_curve(&self, data: &[u8]) -> CurvePoint {
+        let p = self.prime_modulus();
+        let blocks = p.bits().div_ceil(256) as u8;
+
+        for counter in 0..=u32::MAX {
+            let mut digest = Vec::with_capacity(32 * blocks as usize);
+            for block in 0..blocks {
+                let hash = Sha256::new()
+                    .chain_update(counter.to_be_bytes())
+                    .chain_update([block])
+                    .chain_update(data)
+                    .finalize();
+                digest.extend_from_slice(&hash);
+            }
+            let x = BigUint::from_bytes_be(&digest) % &p;
+
+            let rhs = (x.modpow(&BigUint::from(3_u8), &p) + self.a() * &x + self.b()) % &p;
+            if let Some(y) = mod_sqrt(&rhs, &p) {
+                let y = if y.bit(0) { (&p - y) % &p } else { y };
+                return CurvePoint::Affine { x, y };
+            }
+        }
+
+        unreachable!("No curve point found after 2^32 attempts")
+    }
+
+    /// Returns a second generator \( H \) of the prime-order subgroup whose discrete logarithm with respect to
+    /// the generator is unknown, as needed by Pedersen commitments and similar protocols.
+    ///
+    /// \( H \) is [`Curve::hash_to_curve`] of a fixed domain string, multiplied by the cofactor to land in the
+    /// subgroup. Anyone can recompute it, which is what makes it "nothing up my sleeve": the same point is
+    /// returned on every call.
+    fn alternate_generator(&self) -> CurvePoint
+    where
+        Self: Sized,
+    {
+        self.clear_cofactor(self.hash_to_curve(b"shai/alternate-generator"))
+    }
+
+    /// Calculates the public key by scalar multiplication of the secret key with the generator point.
     ///
     /// # Requirements
     /// This implementation requires that the `Curve` trait implementation is `Sized`.
@@ -52,28 +515,69 @@ pub trait Curve {
     where
         Self: Sized, // Add a `Sized` constraint to ensure `self` is a statically sized type
     {
-        let mut result = self.identity(); // Start with the identity element (point at infinity)
-        let mut current = self.generator_point(); // Start with the generator point (G)
-
-        // Iterate over each bit of the secret key
-        for i in 0..secret_key.bits() {
-            // Check if the i-th bit is set
-            if ((secret_key.clone() >> i) & BigUint::from(1u8)) == BigUint::from(1u8) {
-                // Add the current point to the result
-                result = add_two_points(result, current.clone(), self);
-            }
-            // Double the current point
-            current = add_two_points(current.clone(), current, self);
-        }
+        self.scalar_mul(&secret_key, self.generator_point())
+    }
+
+    /// Returns a random point of the subgroup, the generator multiplied by a random scalar in `[1, order)`.
+    ///
+    /// A test helper that is guaranteed to yield a point on the curve, unlike hand-picked coordinates. Only
+    /// built for the crate's own tests and with the `testing` feature.
+    #[cfg(any(test, feature = "testing"))]
+    fn random_point<R: RngCore + ?Sized>(&self, rng: &mut R) -> CurvePoint
+    where
+        Self: Sized,
+    {
+        self.calculate_public_key(self.generate_secret_key_with_rng(rng))
+    }
 
-        result
+    /// Multiplies an arbitrary point by a scalar.
+    ///
+    /// Uses the double-and-add method to perform scalar multiplication:
+    /// 1. Start with the identity point as the result.
+    /// 2. For each bit of the scalar:
+    ///    - If the bit is set, add the current multiple of the point to the result.
+    ///    - Double the current multiple.
+    ///
+    /// The work is done by the free function `arithmetic::scalar_mul`, which also accepts a `&dyn Curve`.
+    fn scalar_mul(&self, scalar: &BigUint, point: CurvePoint) -> CurvePoint
+    where
+        Self: Sized,
+    {
+        scalar_mul(scalar, &point, self)
     }
 }
 
+/// Reduces SHA-512 of `parts` followed by a 32-bit big-endian counter modulo the order, counting up from zero
+/// until the result is non-zero
+pub(crate) fn hash_to_secret<T: Curve + ?Sized>(curve: &T, parts: &[&[u8]]) -> BigUint {
+    (0..=u32::MAX)
+        .map(|counter| {
+            let mut hasher = Sha512::new();
+            for part in parts {
+                hasher.update(part);
+            }
+            hasher.update(counter.to_be_bytes());
+            curve.reduce_scalar(&BigUint::from_bytes_be(&hasher.finalize()))
+        })
+        .find(|secret| *secret != BigUint::ZERO)
+        .expect("No non-zero secret found after 2^32 attempts")
+}
+
+/// Draws a scalar uniformly from `[1, order)`
+fn random_secret<R: RngCore + ?Sized>(order: &BigUint, rng: &mut R) -> BigUint {
+    rng.gen_biguint_range(&BigUint::from(1_u8), order)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::elliptic_curves::{
+        arithmetic::{add_two_points, negate_point},
+        sec1::to_sec1,
+        secp256k1::Secp256k1,
+    };
     use num_bigint::BigUint;
+    use rand::{rngs::StdRng, SeedableRng};
 
     struct DummyCurve;
 
@@ -98,13 +602,90 @@ mod tests {
         }
 
         fn order(&self) -> BigUint {
-            BigUint::from(13u8)
+            BigUint::from(11u8)
+        }
+
+        fn identity(&self) -> CurvePoint {
+            CurvePoint::Infinity
+        }
+    }
+    /// y^2 = x^3 + x + 4 (mod 17) has 14 points: a subgroup of order 7 and cofactor 2.
+    struct CofactorCurve;
+
+    impl Curve for CofactorCurve {
+        fn generator_point(&self) -> CurvePoint {
+            CurvePoint::Affine {
+                x: BigUint::from(4u8),
+                y: BigUint::from(2u8),
+            }
+        }
+
+        fn prime_modulus(&self) -> BigUint {
+            BigUint::from(17u8)
+        }
+
+        fn a(&self) -> BigUint {
+            BigUint::from(1u8)
+        }
+
+        fn b(&self) -> BigUint {
+            BigUint::from(4u8)
+        }
+
+        fn order(&self) -> BigUint {
+            BigUint::from(7u8)
+        }
+
+        fn identity(&self) -> CurvePoint {
+            CurvePoint::Infinity
+        }
+
+        fn cofactor(&self) -> BigUint {
+            BigUint::from(2u8)
+        }
+    }
+
+    /// A curve over p = 17 with configurable (and possibly inconsistent) parameters, where a `None` generator
+    /// is the point at infinity
+    struct BrokenCurve {
+        a: u8,
+        b: u8,
+        generator: Option<(u8, u8)>,
+        order: u8,
+    }
+
+    impl Curve for BrokenCurve {
+        fn generator_point(&self) -> CurvePoint {
+            match self.generator {
+                Some((x, y)) => CurvePoint::Affine {
+                    x: BigUint::from(x),
+                    y: BigUint::from(y),
+                },
+                None => CurvePoint::Infinity,
+            }
+        }
+
+        fn prime_modulus(&self) -> BigUint {
+            BigUint::from(17u8)
+        }
+
+        fn a(&self) -> BigUint {
+            BigUint::from(self.a)
+        }
+
+        fn b(&self) -> BigUint {
+            BigUint::from(self.b)
+        }
+
+        fn order(&self) -> BigUint {
+            BigUint::from(self.order)
         }
 
         fn identity(&self) -> CurvePoint {
             CurvePoint::Infinity
         }
     }
+
     #[test]
     fn test_is_infinity() {
         let infinity_point = CurvePoint::Infinity;
@@ -117,13 +698,339 @@ mod tests {
         assert!(!affine_point.is_infinity());
     }
 
+    #[test]
+    fn test_ordering() {
+        let point = |x: u8, y: u8| CurvePoint::Affine {
+            x: BigUint::from(x),
+            y: BigUint::from(y),
+        };
+        let mut points = vec![
+            point(2, 3),
+            point(1, 6),
+            CurvePoint::Infinity,
+            point(2, 1),
+            point(1, 1),
+        ];
+        points.sort();
+
+        assert_eq!(
+            points,
+            vec![
+                CurvePoint::Infinity,
+                point(1, 1),
+                point(1, 6),
+                point(2, 1),
+                point(2, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_display_and_from_str_round_trip() {
+        let affine_point = CurvePoint::Affine {
+            x: BigUint::from(0xabu8),
+            y: BigUint::from(3u8),
+        };
+
+        assert_eq!(affine_point.to_string(), "(ab, 3)");
+        assert_eq!("(ab, 3)".parse::<CurvePoint>(), Ok(affine_point));
+        assert_eq!(CurvePoint::Infinity.to_string(), "Infinity");
+        assert_eq!("Infinity".parse::<CurvePoint>(), Ok(CurvePoint::Infinity));
+
+        let generator = DummyCurve.generator_point();
+        assert_eq!(generator.to_string().parse::<CurvePoint>(), Ok(generator));
+    }
+
+    #[test]
+    fn test_from_str_rejects_malformed_input() {
+        for input in [
+            "",
+            "infinity",
+            "(ab 3)",
+            "(ab, 3",
+            "ab, 3)",
+            "(, 3)",
+            "(ab, )",
+            "(xy, 3)",
+            "(ab, 3, 4)",
+            "(+ab, 3)",
+            "( ab, 3)",
+        ] {
+            assert_eq!(
+                input.parse::<CurvePoint>(),
+                Err(Error::MalformedPoint),
+                "{input:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_field_byte_size() {
+        assert_eq!(DummyCurve.field_byte_size(), 1);
+    }
+
+    #[test]
+    fn test_new_affine() {
+        let curve = Secp256k1;
+        let CurvePoint::Affine { x, y } = curve.generator_point() else {
+            unreachable!()
+        };
+        let p = curve.prime_modulus();
+
+        assert_eq!(
+            CurvePoint::new_affine(x.clone(), y.clone(), &curve),
+            Ok(curve.generator_point())
+        );
+        // Unreduced coordinates are reduced
+        assert_eq!(
+            CurvePoint::new_affine(&x + &p, &y + &p * 2_u8, &curve),
+            Ok(curve.generator_point())
+        );
+        assert_eq!(
+            CurvePoint::new_affine(x.clone(), y + 1_u8, &curve),
+            Err(Error::PointNotOnCurve)
+        );
+        assert_eq!(
+            CurvePoint::new_affine(BigUint::ZERO, BigUint::ZERO, &DummyCurve),
+            Err(Error::PointNotOnCurve)
+        );
+    }
+
+    #[test]
+    fn test_order_sizes() {
+        // The order 11 has 4 bits
+        assert_eq!(DummyCurve.order_bits(), 4);
+        assert_eq!(DummyCurve.order_byte_size(), 1);
+        assert_eq!(Secp256k1.order_bits(), 256);
+        assert_eq!(Secp256k1.order_byte_size(), 32);
+    }
+
+    #[test]
+    fn test_half_order() {
+        fn check<T: Curve>(curve: T) {
+            let (half_order, order) = (curve.half_order(), curve.order());
+            assert!(&half_order * 2u8 == order || &half_order * 2u8 + 1u8 == order);
+        }
+
+        assert_eq!(DummyCurve.half_order(), BigUint::from(5u8));
+        check(DummyCurve);
+        check(CofactorCurve);
+        check(Secp256k1);
+    }
+
+    #[test]
+    fn test_security_bits() {
+        // The order 11 has 4 bits
+        assert_eq!(DummyCurve.security_bits(), 2);
+        // The order 7 has 3 bits, rounded down
+        assert_eq!(CofactorCurve.security_bits(), 1);
+    }
+
     #[test]
     fn test_generate_secret_key() {
         let curve = DummyCurve;
-        let secret_key = curve.generate_secret_key();
+        let secret_key = curve.generate_secret_key_with_rng(&mut StdRng::seed_from_u64(0));
 
         assert!(secret_key < curve.order());
         assert!(secret_key != BigUint::ZERO); // Ensure the secret key is not zero
+
+        // The same seed yields the same keys, so tests can assert exact values instead of just ranges
+        let keys = |seed| {
+            let rng = &mut StdRng::seed_from_u64(seed);
+            (0..8)
+                .map(|_| curve.generate_secret_key_with_rng(rng))
+                .collect::<Vec<_>>()
+        };
+
+        let expected: Vec<_> = [9_u8, 1, 10, 5, 2, 2, 4, 10].map(BigUint::from).into();
+        assert_eq!(keys(0), expected);
+        assert_ne!(keys(1), expected);
+    }
+
+    #[test]
+    fn test_double_matches_self_addition() {
+        let curve = CofactorCurve;
+        let points = [
+            curve.generator_point(),
+            curve.calculate_public_key(BigUint::from(3u8)),
+            // The 2-torsion point (3, 0) doubles to infinity
+            CurvePoint::Affine {
+                x: BigUint::from(3u8),
+                y: BigUint::ZERO,
+            },
+            CurvePoint::Infinity,
+        ];
+
+        for point in points {
+            let doubled = curve.double(point.clone());
+            assert!(curve.is_on_curve(&doubled));
+            assert_eq!(
+                doubled,
+                add_two_points(point.clone(), point.clone(), &curve)
+            );
+            // 2P - P goes through the addition formula only
+            assert_eq!(
+                add_two_points(doubled, negate_point(point.clone(), &curve), &curve),
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn test_decompress_candidates() {
+        let curve = CofactorCurve;
+
+        for x in 0..17u8 {
+            let x = BigUint::from(x);
+            let Some((even, odd)) = curve.decompress_candidates(&x) else {
+                // No point has this x-coordinate
+                assert!((0..17u8).all(|y| !curve.is_on_curve(&CurvePoint::Affine {
+                    x: x.clone(),
+                    y: BigUint::from(y)
+                })));
+                continue;
+            };
+
+            assert!(curve.is_on_curve(&even));
+            assert!(curve.is_on_curve(&odd));
+            assert_eq!(negate_point(even.clone(), &curve), odd);
+            let (CurvePoint::Affine { y: even_y, .. }, CurvePoint::Affine { y: odd_y, .. }) =
+                (&even, &odd)
+            else {
+                unreachable!()
+            };
+            // The 2-torsion point (3, 0) is its own negation
+            assert!(!even_y.bit(0));
+            assert!(odd_y.bit(0) || *odd_y == BigUint::ZERO);
+        }
+        assert_eq!(curve.decompress_candidates(&BigUint::from(17u8)), None);
+    }
+
+    #[test]
+    fn test_reduce_scalar() {
+        let curve = Secp256k1;
+        let n = curve.order();
+        let value = BigUint::from(5_u8);
+
+        assert_eq!(curve.reduce_scalar(&value), value);
+        assert_eq!(curve.reduce_scalar(&n), BigUint::ZERO);
+        assert_eq!(curve.reduce_scalar(&(&n + &value)), value);
+        assert_eq!(curve.reduce_scalar(&(&n * 3_u8 + &value)), value);
+    }
+
+    #[test]
+    fn test_negate_scalar() {
+        let curve = Secp256k1;
+        let n = curve.order();
+
+        assert_eq!(curve.negate_scalar(&BigUint::ZERO), BigUint::ZERO);
+        assert_eq!(curve.negate_scalar(&n), BigUint::ZERO);
+        assert_eq!(curve.negate_scalar(&BigUint::from(1_u8)), &n - 1_u8);
+        for s in [
+            BigUint::from(1_u8),
+            BigUint::from(0xdead_beef_u32),
+            &n - 1_u8,
+            &n + 5_u8,
+            &n * 3_u8 + 7_u8,
+        ] {
+            let negated = curve.negate_scalar(&s);
+            assert!(negated < n);
+            assert_eq!(curve.negate_scalar(&negated), curve.reduce_scalar(&s));
+            assert_eq!(curve.reduce_scalar(&(&s + negated)), BigUint::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_lift_x_parity() {
+        let curve = Secp256k1;
+
+        for k in 1..32u8 {
+            let CurvePoint::Affine { x, .. } = curve.calculate_public_key(BigUint::from(k)) else {
+                unreachable!()
+            };
+            let even = curve.lift_x(&x, true).unwrap();
+            let odd = curve.lift_x(&x, false).unwrap();
+
+            let (CurvePoint::Affine { y: even_y, .. }, CurvePoint::Affine { y: odd_y, .. }) =
+                (&even, &odd)
+            else {
+                unreachable!()
+            };
+            assert!(!even_y.bit(0));
+            assert!(odd_y.bit(0));
+            assert!(curve.is_on_curve(&even));
+            assert!(curve.is_negative_of(&even, &odd));
+        }
+
+        // x = 5 is not a valid coordinate on secp256k1, since 5^3 + 7 = 132 is not a square
+        assert_eq!(curve.lift_x(&BigUint::from(5u8), true), None);
+        assert_eq!(curve.lift_x(&curve.prime_modulus(), true), None);
+    }
+
+    #[test]
+    fn test_enumerate_points() {
+        let points = DummyCurve.enumerate_points();
+        assert_eq!(BigUint::from(points.len()), DummyCurve.order());
+        assert_eq!(points[0], CurvePoint::Infinity);
+        assert!(points.iter().all(|point| DummyCurve.is_on_curve(point)));
+        assert!(points.contains(&DummyCurve.generator_point()));
+
+        // Including the 2-torsion point (3, 0), counted once
+        let points = CofactorCurve.enumerate_points();
+        assert_eq!(
+            BigUint::from(points.len()),
+            CofactorCurve.order() * CofactorCurve.cofactor()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 16 bits")]
+    fn test_enumerate_points_rejects_large_curves() {
+        Secp256k1.enumerate_points();
+    }
+
+    #[test]
+    fn test_normalize() {
+        let curve = DummyCurve;
+        let generator = curve.generator_point();
+        let unreduced = CurvePoint::Affine {
+            x: BigUint::from(2u8 + 7),
+            y: BigUint::from(3u8 + 3 * 7),
+        };
+
+        assert!(!curve.is_on_curve(&unreduced));
+        assert_eq!(curve.normalize(unreduced), generator);
+        assert_eq!(curve.normalize(generator.clone()), generator);
+        assert_eq!(curve.normalize(CurvePoint::Infinity), CurvePoint::Infinity);
+    }
+
+    #[test]
+    fn test_point_from_bytes() {
+        let curve = CofactorCurve;
+        for point in curve.enumerate_points() {
+            for compressed in [false, true] {
+                let bytes = to_sec1(&point, compressed, &curve);
+                assert_eq!(curve.point_from_bytes(&bytes), Ok(point.clone()));
+            }
+        }
+
+        assert_eq!(
+            curve.point_from_bytes(&[0x04, 4, 3]),
+            Err(Error::PointNotOnCurve)
+        );
+        assert_eq!(curve.point_from_bytes(&[]), Err(Error::InvalidEncoding));
+    }
+
+    #[test]
+    fn test_is_valid_scalar() {
+        let curve = DummyCurve;
+        let order = curve.order();
+
+        assert!(!curve.is_valid_scalar(&BigUint::ZERO));
+        assert!(curve.is_valid_scalar(&BigUint::from(1u8)));
+        assert!(curve.is_valid_scalar(&(&order - 1u8)));
+        assert!(!curve.is_valid_scalar(&order));
+        assert!(!curve.is_valid_scalar(&(order + 1u8)));
     }
 
     #[test]
@@ -143,6 +1050,285 @@ mod tests {
         assert_eq!(public_key, expected_public_key);
     }
 
+    #[test]
+    fn test_scalar_mul_matches_repeated_addition() {
+        let curve = CofactorCurve;
+        let generator = curve.generator_point();
+
+        let mut expected = CurvePoint::Infinity;
+        for k in 0..40u8 {
+            assert_eq!(
+                curve.scalar_mul(&BigUint::from(k), generator.clone()),
+                expected
+            );
+            expected = add_two_points(expected, generator.clone(), &curve);
+        }
+    }
+
+    #[test]
+    fn test_scalar_mul_through_infinity() {
+        fn check<T: Curve>(curve: T) {
+            let generator = curve.generator_point();
+            let order = curve.order();
+
+            assert_eq!(
+                curve.scalar_mul(&order, generator.clone()),
+                CurvePoint::Infinity
+            );
+            assert_eq!(
+                curve.calculate_public_key(order.clone()),
+                CurvePoint::Infinity
+            );
+            assert_eq!(
+                curve.scalar_mul(&(&order - 1_u8), generator.clone()),
+                negate_point(generator.clone(), &curve)
+            );
+            assert_eq!(
+                curve.scalar_mul(&(&order + 1_u8), generator.clone()),
+                generator
+            );
+            assert_eq!(
+                curve.scalar_mul(&(&order * 2_u8), generator),
+                CurvePoint::Infinity
+            );
+        }
+
+        check(DummyCurve);
+        check(CofactorCurve);
+        check(Secp256k1);
+    }
+
+    #[test]
+    fn test_is_on_curve() {
+        let curve = DummyCurve;
+
+        assert!(curve.is_on_curve(&curve.generator_point()));
+        assert!(curve.is_on_curve(&CurvePoint::Infinity));
+        assert!(!curve.is_on_curve(&CurvePoint::Affine {
+            x: BigUint::from(2u8),
+            y: BigUint::from(2u8),
+        }));
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        let curve = DummyCurve;
+
+        for data in [&b""[..], b"hello", b"shai-hulud"] {
+            let point = curve.hash_to_curve(data);
+
+            assert!(!point.is_infinity());
+            assert!(curve.is_on_curve(&point));
+            assert_eq!(point, curve.hash_to_curve(data));
+        }
+    }
+
+    #[test]
+    fn test_alternate_generator() {
+        let curve = Secp256k1;
+        let h = curve.alternate_generator();
+
+        assert_eq!(h, curve.alternate_generator());
+        assert!(!h.is_infinity());
+        assert!(curve.is_on_curve(&h));
+        assert_ne!(h, curve.generator_point());
+
+        // Cleared into the subgroup of order 7 on a curve with cofactor 2
+        let h = CofactorCurve.alternate_generator();
+        assert!(!h.is_infinity());
+        assert!(CofactorCurve.is_in_subgroup(&h));
+    }
+
+    #[test]
+    fn test_dyn_curves() {
+        use alloc::boxed::Box;
+
+        use crate::elliptic_curves::{generic::GenericCurve, p256::P256, p384::P384};
+
+        let small = |value: u8| BigUint::from(value);
+        let curves: Vec<Box<dyn Curve>> = vec![
+            Box::new(Secp256k1),
+            Box::new(P256),
+            Box::new(P384),
+            Box::new(GenericCurve::new(
+                small(17),
+                small(2),
+                small(2),
+                small(5),
+                small(1),
+                small(19),
+                small(1),
+            )),
+        ];
+
+        let sizes: Vec<_> = curves.iter().map(|curve| curve.field_byte_size()).collect();
+        assert_eq!(sizes, [32, 32, 48, 1]);
+        let names: Vec<_> = curves.iter().map(|curve| curve.name()).collect();
+        assert_eq!(names, ["secp256k1", "P-256", "P-384", "custom"]);
+        for curve in &curves {
+            let generator = curve.generator_point();
+            assert!(curve.is_on_curve(&generator));
+            assert!(curve.is_on_curve(&curve.double(generator)));
+            assert!(curve.is_on_curve(&curve.hash_to_curve(b"hello")));
+            assert!(curve.is_valid_scalar(&curve.generate_secret_key()));
+        }
+    }
+
+    #[test]
+    fn test_clear_cofactor() {
+        let curve = CofactorCurve;
+        let torsion = CurvePoint::Affine {
+            x: BigUint::from(3u8),
+            y: BigUint::from(0u8),
+        };
+        let generator = curve.generator_point();
+
+        assert_eq!(curve.clear_cofactor(torsion.clone()), CurvePoint::Infinity);
+        // G + T has a torsion component, which clearing removes: 2(G + T) = 2G
+        let mixed = add_two_points(generator.clone(), torsion, &curve);
+        assert!(!curve.is_in_subgroup(&mixed));
+        let cleared = curve.clear_cofactor(mixed);
+        assert!(curve.is_in_subgroup(&cleared));
+        assert_eq!(cleared, curve.double(generator));
+
+        // A no-op with the default cofactor of 1
+        let point = Secp256k1.calculate_public_key(BigUint::from(5u8));
+        assert_eq!(Secp256k1.clear_cofactor(point.clone()), point);
+    }
+
+    #[test]
+    fn test_is_negative_of() {
+        let curve = Secp256k1;
+        let point = curve.calculate_public_key(BigUint::from(5u8));
+        let negated = negate_point(point.clone(), &curve);
+
+        assert!(curve.is_negative_of(&point, &negated));
+        assert!(curve.is_negative_of(&negated, &point));
+        assert!(!curve.is_negative_of(&point, &point));
+        assert!(!curve.is_negative_of(&point, &curve.generator_point()));
+        assert!(!curve.is_negative_of(&point, &CurvePoint::Infinity));
+        assert!(curve.is_negative_of(&CurvePoint::Infinity, &CurvePoint::Infinity));
+
+        // (3, 0) has order 2 and is its own negative
+        let torsion = CurvePoint::Affine {
+            x: BigUint::from(3u8),
+            y: BigUint::from(0u8),
+        };
+        assert!(CofactorCurve.is_negative_of(&torsion, &torsion));
+    }
+
+    #[test]
+    fn test_generate_secret_key_deterministic() {
+        let curve = Secp256k1;
+        let keys: Vec<_> = (0..32)
+            .map(|index| curve.generate_secret_key_deterministic(index))
+            .collect();
+
+        for (index, key) in keys.iter().enumerate() {
+            assert!(curve.is_valid_scalar(key));
+            assert_eq!(&curve.generate_secret_key_deterministic(index as u64), key);
+            assert!(!keys[index + 1..].contains(key));
+        }
+
+        // Reduced into a tiny order as well
+        for index in 0..32 {
+            assert!(
+                DummyCurve.is_valid_scalar(&DummyCurve.generate_secret_key_deterministic(index))
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_cofactor() {
+        assert_eq!(DummyCurve.cofactor(), BigUint::from(1u8));
+    }
+
+    #[test]
+    fn test_is_in_subgroup() {
+        let curve = CofactorCurve;
+        // (3, 0) has order 2, so it lies outside the subgroup of order 7
+        let torsion = CurvePoint::Affine {
+            x: BigUint::from(3u8),
+            y: BigUint::from(0u8),
+        };
+
+        assert!(curve.is_on_curve(&torsion));
+        assert!(curve.is_in_subgroup(&curve.generator_point()));
+        assert!(curve.is_in_subgroup(&curve.calculate_public_key(BigUint::from(3u8))));
+        assert!(!curve.is_in_subgroup(&torsion));
+        assert!(!curve.is_in_subgroup(&add_two_points(curve.generator_point(), torsion, &curve)));
+    }
+
+    #[test]
+    fn test_discriminant() {
+        // -16 (4 + 27 * 36) = -15616 = 1 (mod 7)
+        assert_eq!(DummyCurve.discriminant(), BigUint::from(1u8));
+        // -16 (27 * 49) = -21168
+        assert_eq!(
+            Secp256k1.discriminant(),
+            Secp256k1.prime_modulus() - 21168u32
+        );
+        assert_ne!(CofactorCurve.discriminant(), BigUint::ZERO);
+
+        let singular = BrokenCurve {
+            a: 0,
+            b: 0,
+            generator: Some((1, 1)),
+            order: 17,
+        };
+        assert_eq!(singular.discriminant(), BigUint::ZERO);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(CofactorCurve.validate(), Ok(()));
+
+        // y^2 = x^3 is singular
+        let singular = BrokenCurve {
+            a: 0,
+            b: 0,
+            generator: Some((1, 1)),
+            order: 17,
+        };
+        assert_eq!(singular.validate(), Err(Error::SingularCurve));
+
+        let off_curve = BrokenCurve {
+            a: 1,
+            b: 4,
+            generator: Some((4, 3)),
+            order: 7,
+        };
+        assert_eq!(off_curve.validate(), Err(Error::GeneratorNotOnCurve));
+
+        let wrong_order = BrokenCurve {
+            a: 1,
+            b: 4,
+            generator: Some((4, 2)),
+            order: 13,
+        };
+        assert_eq!(wrong_order.validate(), Err(Error::InvalidGeneratorOrder));
+
+        let identity = BrokenCurve {
+            a: 1,
+            b: 4,
+            generator: None,
+            order: 7,
+        };
+        assert_eq!(identity.validate(), Err(Error::GeneratorIsIdentity));
+
+        // 28 is a multiple of the order 7 of (4, 2), so 28G = O, but the curve has only 18 +- 8 points
+        let multiple_order = BrokenCurve {
+            a: 1,
+            b: 4,
+            generator: Some((4, 2)),
+            order: 28,
+        };
+        assert_eq!(
+            multiple_order.validate(),
+            Err(Error::OrderOutsideHasseBound)
+        );
+    }
+
     #[test]
     fn test_identity() {
         let curve = DummyCurve;
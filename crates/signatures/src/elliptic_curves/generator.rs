@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+
+use super::{
+    arithmetic::add_two_points,
+    curve::{Curve, CurvePoint},
+};
+
+/// The generator of a curve and its first few multiples \( G, 2G, \ldots, mG \), computed once.
+///
+/// `Curve::generator_point` builds a fresh point on every call, which for most curves means parsing the
+/// coordinates again. Code that needs the generator (or a small multiple of it) many times, such as
+/// precomputed tables, can hold on to a `Generator` and borrow the points instead.
+pub struct Generator<T: Curve> {
+    curve: T,
+    multiples: Vec<CurvePoint>,
+}
+
+impl<T: Curve> Generator<T> {
+    /// Computes \( G, 2G, \ldots, mG \) for the generator \( G \) of `curve` and \( m \) = `count`.
+    ///
+    /// # Panics
+    /// - If `count` is zero.
+    pub fn new(curve: T, count: usize) -> Self {
+        assert!(count > 0, "count must be at least 1");
+        let generator = curve.generator_point();
+
+        let mut multiples = Vec::with_capacity(count);
+        multiples.push(generator.clone());
+        for _ in 1..count {
+            let next = add_two_points(
+                multiples[multiples.len() - 1].clone(),
+                generator.clone(),
+                &curve,
+            );
+            multiples.push(next);
+        }
+
+        Self { curve, multiples }
+    }
+
+    /// Returns the curve
+    pub fn curve(&self) -> &T {
+        &self.curve
+    }
+
+    /// Returns the generator \( G \)
+    pub fn point(&self) -> &CurvePoint {
+        &self.multiples[0]
+    }
+
+    /// Returns \( kG \) for \( k \in [1, m] \), or `None` outside that range
+    pub fn multiple(&self, k: usize) -> Option<&CurvePoint> {
+        k.checked_sub(1).and_then(|index| self.multiples.get(index))
+    }
+
+    /// Returns \( G, 2G, \ldots, mG \)
+    pub fn multiples(&self) -> &[CurvePoint] {
+        &self.multiples
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::Generator;
+    use crate::elliptic_curves::{
+        curve::{Curve, CurvePoint},
+        generic::GenericCurve,
+        p256::P256,
+        secp256k1::Secp256k1,
+    };
+
+    #[test]
+    fn test_multiples_match_calculate_public_key() {
+        fn check<T: Curve>(curve: T) {
+            let generator = Generator::new(curve, 8);
+
+            assert_eq!(generator.point(), &generator.curve().generator_point());
+            assert_eq!(generator.multiples().len(), 8);
+            for k in 1..=8 {
+                assert_eq!(
+                    generator.multiple(k),
+                    Some(&generator.curve().calculate_public_key(BigUint::from(k)))
+                );
+            }
+            assert_eq!(generator.multiple(0), None);
+            assert_eq!(generator.multiple(9), None);
+        }
+
+        check(Secp256k1);
+        check(P256);
+    }
+
+    #[test]
+    fn test_multiples_wrap_around_small_order() {
+        let curve = GenericCurve::toy();
+        let generator = Generator::new(curve, 20);
+
+        assert_eq!(generator.multiple(19), Some(&CurvePoint::Infinity));
+        assert_eq!(generator.multiple(20), Some(generator.point()));
+    }
+
+    #[test]
+    #[should_panic(expected = "count must be at least 1")]
+    fn test_rejects_zero_count() {
+        Generator::new(Secp256k1, 0);
+    }
+}
@@ -0,0 +1,151 @@
+use num_bigint::BigUint;
+
+use super::curve::{Curve, CurvePoint};
+use crate::error::Error;
+
+/// A short Weierstrass curve \( y^2 = x^3 + ax + b \mod p \) whose parameters are provided at runtime.
+///
+/// Handy for experimenting with arbitrary (e.g. small) curves without writing an `impl Curve` for each.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenericCurve {
+    pub p: BigUint,
+    pub a: BigUint,
+    pub b: BigUint,
+    pub gx: BigUint,
+    pub gy: BigUint,
+    pub n: BigUint,
+    pub cofactor: BigUint,
+}
+
+impl GenericCurve {
+    /// Creates a curve from its parameters without checking them, see [`GenericCurve::validated`].
+    pub fn new(
+        p: BigUint,
+        a: BigUint,
+        b: BigUint,
+        gx: BigUint,
+        gy: BigUint,
+        n: BigUint,
+        cofactor: BigUint,
+    ) -> Self {
+        Self {
+            p,
+            a,
+            b,
+            gx,
+            gy,
+            n,
+            cofactor,
+        }
+    }
+
+    /// The toy curve \( y^2 = x^3 + 2x + 2 \mod 17 \), whose generator (5, 1) has prime order 19.
+    ///
+    /// Small enough to check results by hand, so it's shared by the crate's tests and the fuzz targets. Only
+    /// built for the crate's own tests and with the `testing` feature.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn toy() -> Self {
+        let b = |value: u8| BigUint::from(value);
+        Self::new(b(17), b(2), b(2), b(5), b(1), b(19), b(1))
+    }
+
+    /// Runs [`Curve::validate`] on the parameters, returning the curve if they describe a usable group.
+    ///
+    /// # Errors
+    /// - Any error raised by `validate`.
+    pub fn validated(self) -> Result<Self, Error> {
+        self.validate()?;
+        Ok(self)
+    }
+}
+
+impl Curve for GenericCurve {
+    fn generator_point(&self) -> CurvePoint {
+        CurvePoint::Affine {
+            x: self.gx.clone(),
+            y: self.gy.clone(),
+        }
+    }
+
+    fn prime_modulus(&self) -> BigUint {
+        self.p.clone()
+    }
+
+    fn a(&self) -> BigUint {
+        self.a.clone()
+    }
+
+    fn b(&self) -> BigUint {
+        self.b.clone()
+    }
+
+    fn order(&self) -> BigUint {
+        self.n.clone()
+    }
+
+    fn identity(&self) -> CurvePoint {
+        CurvePoint::Infinity
+    }
+
+    fn cofactor(&self) -> BigUint {
+        self.cofactor.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::GenericCurve;
+    use crate::{
+        elliptic_curves::{curve::Curve, secp256k1::Secp256k1},
+        error::Error,
+    };
+
+    fn hex(value: &[u8]) -> BigUint {
+        BigUint::parse_bytes(value, 16).unwrap()
+    }
+
+    #[test]
+    fn test_matches_hard_coded_secp256k1() {
+        let curve = GenericCurve::new(
+            hex(b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F"),
+            BigUint::ZERO,
+            BigUint::from(7_u8),
+            hex(b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"),
+            hex(b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8"),
+            hex(b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"),
+            BigUint::from(1_u8),
+        )
+        .validated()
+        .unwrap();
+
+        assert_eq!(curve.generator_point(), Secp256k1.generator_point());
+        assert_eq!(curve.order(), Secp256k1.order());
+        assert_eq!(curve.prime_modulus(), Secp256k1.prime_modulus());
+        assert_eq!(
+            curve.calculate_public_key(BigUint::from(12345_u32)),
+            Secp256k1.calculate_public_key(BigUint::from(12345_u32))
+        );
+    }
+
+    #[test]
+    fn test_validated_small_curve() {
+        let wrong_order = GenericCurve {
+            n: BigUint::from(18_u8),
+            ..GenericCurve::toy()
+        };
+        assert_eq!(GenericCurve::toy().validated(), Ok(GenericCurve::toy()));
+        assert_eq!(wrong_order.validated(), Err(Error::InvalidGeneratorOrder));
+    }
+
+    #[test]
+    fn test_new_does_not_validate() {
+        let curve = GenericCurve {
+            n: BigUint::from(18_u8),
+            ..GenericCurve::toy()
+        };
+        assert_eq!(curve.order(), BigUint::from(18_u8));
+        assert_eq!(curve.cofactor(), BigUint::from(1_u8));
+    }
+}
@@ -0,0 +1,243 @@
+use num_bigint::BigUint;
+
+use super::{
+    arithmetic::{mod_inv, mod_sub},
+    curve::{Curve, CurvePoint},
+};
+
+/// A point in Jacobian coordinates \( (X : Y : Z) \), representing the affine point
+/// \( (X / Z^2, Y / Z^3) \). Any \( Z = 0 \) represents the point at infinity.
+///
+/// The same point has \( p - 1 \) representations (one for every non-zero \( \lambda \) in
+/// \( (\lambda^2 X : \lambda^3 Y : \lambda Z) \)), so the coordinates can't be compared directly. Comparing them
+/// requires the prime modulus, which is why there is no `PartialEq` and [`JacobianPoint::equals`] takes the curve.
+#[derive(Clone, Debug)]
+pub struct JacobianPoint {
+    pub x: BigUint,
+    pub y: BigUint,
+    pub z: BigUint,
+}
+
+impl JacobianPoint {
+    /// Returns the representation \( (1 : 1 : 0) \) of the point at infinity
+    pub fn infinity() -> Self {
+        Self {
+            x: BigUint::from(1_u8),
+            y: BigUint::from(1_u8),
+            z: BigUint::ZERO,
+        }
+    }
+
+    /// Check if the point is the point at infinity
+    pub fn is_infinity(&self) -> bool {
+        self.z == BigUint::ZERO
+    }
+
+    /// Checks whether two points are the same point of `curve`, regardless of their representation.
+    ///
+    /// Cross-multiplies instead of converting to affine coordinates, which would need two inversions:
+    /// \( X_1 Z_2^2 = X_2 Z_1^2 \) and \( Y_1 Z_2^3 = Y_2 Z_1^3 \mod p \).
+    pub fn equals<T: Curve>(&self, other: &Self, curve: &T) -> bool {
+        let p = curve.prime_modulus();
+        let z1 = &self.z % &p;
+        let z2 = &other.z % &p;
+
+        match (z1 == BigUint::ZERO, z2 == BigUint::ZERO) {
+            (true, true) => true,
+            (true, false) | (false, true) => false,
+            (false, false) => {
+                let z1_squared = (&z1 * &z1) % &p;
+                let z2_squared = (&z2 * &z2) % &p;
+                let x_matches = (&self.x * &z2_squared) % &p == (&other.x * &z1_squared) % &p;
+                let y_matches =
+                    (&self.y * z2_squared * z2) % &p == (&other.y * z1_squared * z1) % &p;
+                x_matches && y_matches
+            }
+        }
+    }
+
+    /// Checks whether this point is the affine point `point` of `curve`
+    pub fn equals_affine<T: Curve>(&self, point: &CurvePoint, curve: &T) -> bool {
+        self.equals(&to_jacobian(point), curve)
+    }
+
+    /// Doubles the point without any modular inversion.
+    ///
+    /// With \( S = 4XY^2 \) and \( M = 3X^2 + aZ^4 \):
+    /// - \( X' = M^2 - 2S \)
+    /// - \( Y' = M(S - X') - 8Y^4 \)
+    /// - \( Z' = 2YZ \)
+    pub fn double<T: Curve>(&self, curve: &T) -> Self {
+        let p = curve.prime_modulus();
+        if self.is_infinity() || &self.y % &p == BigUint::ZERO {
+            return Self::infinity();
+        }
+
+        let y_squared = (&self.y * &self.y) % &p;
+        let z_squared = (&self.z * &self.z) % &p;
+        let s = (BigUint::from(4_u8) * &self.x * &y_squared) % &p;
+        let m =
+            (BigUint::from(3_u8) * &self.x * &self.x + curve.a() * &z_squared * &z_squared) % &p;
+
+        let x = mod_sub(&(&m * &m), &(BigUint::from(2_u8) * &s), &p);
+        let y = mod_sub(
+            &(&m * mod_sub(&s, &x, &p)),
+            &(BigUint::from(8_u8) * &y_squared * &y_squared),
+            &p,
+        );
+        let z = (BigUint::from(2_u8) * &self.y * &self.z) % &p;
+        Self { x, y, z }
+    }
+}
+
+/// Converts an affine point to Jacobian coordinates \( (x : y : 1) \), or \( (1 : 1 : 0) \) for the point at
+/// infinity.
+pub fn to_jacobian(point: &CurvePoint) -> JacobianPoint {
+    match point {
+        CurvePoint::Affine { x, y } => JacobianPoint {
+            x: x.clone(),
+            y: y.clone(),
+            z: BigUint::from(1_u8),
+        },
+        CurvePoint::Infinity => JacobianPoint::infinity(),
+    }
+}
+
+/// Converts a Jacobian point back to affine coordinates \( (X / Z^2, Y / Z^3) \).
+///
+/// This is where the single modular inversion of \( Z \) happens that the Jacobian formulas defer. Any point
+/// with \( Z = 0 \mod p \) converts to the point at infinity.
+pub fn to_affine<T: Curve>(point: &JacobianPoint, curve: &T) -> CurvePoint {
+    let p = curve.prime_modulus();
+    let z = &point.z % &p;
+    if z == BigUint::ZERO {
+        return CurvePoint::Infinity;
+    }
+
+    let z_inv = mod_inv(z, &p);
+    let z_inv_squared = (&z_inv * &z_inv) % &p;
+    CurvePoint::Affine {
+        x: (&point.x * &z_inv_squared) % &p,
+        y: (&point.y * z_inv_squared * z_inv) % &p,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::{to_affine, to_jacobian, JacobianPoint};
+    use crate::elliptic_curves::{
+        arithmetic::add_two_points,
+        curve::{Curve, CurvePoint},
+        p256::P256,
+        secp256k1::Secp256k1,
+    };
+
+    fn scaled<T: Curve>(point: &CurvePoint, lambda: u32, curve: &T) -> JacobianPoint {
+        let CurvePoint::Affine { x, y } = point else {
+            unreachable!()
+        };
+        let p = curve.prime_modulus();
+        let lambda = BigUint::from(lambda);
+
+        JacobianPoint {
+            x: (x * &lambda * &lambda) % &p,
+            y: (y * &lambda * &lambda * &lambda) % &p,
+            z: lambda % &p,
+        }
+    }
+
+    #[test]
+    fn test_equal_across_representations() {
+        let curve = Secp256k1;
+        let generator = curve.generator_point();
+
+        let first = scaled(&generator, 1, &curve);
+        let second = scaled(&generator, 12345, &curve);
+        assert_ne!(first.z, second.z);
+        assert!(first.equals(&second, &curve));
+        assert!(second.equals(&first, &curve));
+        assert!(second.equals_affine(&generator, &curve));
+    }
+
+    #[test]
+    fn test_doubling_matches_affine_addition() {
+        fn check<T: Curve>(curve: T) {
+            let generator = curve.generator_point();
+            let affine = add_two_points(generator.clone(), generator.clone(), &curve);
+
+            // Computed from two different starting representations, ending with different z
+            let first = scaled(&generator, 1, &curve).double(&curve);
+            let second = scaled(&generator, 7, &curve).double(&curve);
+            assert_ne!(first.z, second.z);
+            assert!(first.equals(&second, &curve));
+            assert!(first.equals_affine(&affine, &curve));
+            assert!(second.equals_affine(&affine, &curve));
+        }
+
+        check(Secp256k1);
+        check(P256);
+    }
+
+    #[test]
+    fn test_distinct_points_are_not_equal() {
+        let curve = Secp256k1;
+        let generator = curve.generator_point();
+        let doubled = scaled(&generator, 3, &curve).double(&curve);
+
+        assert!(!doubled.equals_affine(&generator, &curve));
+        assert!(!doubled.equals(&JacobianPoint::infinity(), &curve));
+        assert!(JacobianPoint::infinity().equals_affine(&CurvePoint::Infinity, &curve));
+
+        let infinity = JacobianPoint {
+            x: BigUint::from(5_u8),
+            y: BigUint::from(9_u8),
+            z: BigUint::ZERO,
+        };
+        assert!(infinity.equals(&JacobianPoint::infinity(), &curve));
+    }
+
+    #[test]
+    fn test_affine_round_trip() {
+        fn check<T: Curve>(curve: T) {
+            let generator = curve.generator_point();
+            let jacobian = to_jacobian(&generator);
+            assert_eq!(jacobian.z, BigUint::from(1_u8));
+            assert_eq!(to_affine(&jacobian, &curve), generator);
+
+            // Any representation converts back to the same affine point
+            assert_eq!(
+                to_affine(&scaled(&generator, 12345, &curve), &curve),
+                generator
+            );
+
+            let doubled = to_jacobian(&generator).double(&curve);
+            assert_eq!(
+                to_affine(&doubled, &curve),
+                add_two_points(generator.clone(), generator, &curve)
+            );
+        }
+
+        check(Secp256k1);
+        check(P256);
+    }
+
+    #[test]
+    fn test_infinity_round_trip() {
+        let curve = Secp256k1;
+        assert!(to_jacobian(&CurvePoint::Infinity).is_infinity());
+        assert_eq!(
+            to_affine(&JacobianPoint::infinity(), &curve),
+            CurvePoint::Infinity
+        );
+
+        // z = p is zero modulo p
+        let infinity = JacobianPoint {
+            x: BigUint::from(5_u8),
+            y: BigUint::from(9_u8),
+            z: curve.prime_modulus(),
+        };
+        assert_eq!(to_affine(&infinity, &curve), CurvePoint::Infinity);
+    }
+}
@@ -1,3 +1,17 @@
 pub mod arithmetic;
+pub mod brainpool;
+pub mod comb;
+pub mod constant_time;
 pub mod curve;
+pub mod generator;
+pub mod generic;
+pub mod jacobian;
+pub mod montgomery;
+pub mod ops;
+pub mod p256;
+pub mod p384;
+#[cfg(feature = "serde")]
+pub mod point_serde;
+pub mod scalar;
+pub mod sec1;
 pub mod secp256k1;
@@ -0,0 +1,211 @@
+use num_bigint::BigUint;
+
+use super::{arithmetic::mod_inv, constant_time::cswap_uint};
+
+/// A curve in Montgomery form \( By^2 = x^3 + Ax^2 + x \) over the prime field \( \mathbb{F}_p \).
+///
+/// Montgomery curves are not in short Weierstrass form, so they don't implement [`Curve`](super::curve::Curve).
+/// Their arithmetic only needs the x-coordinate (called `u`), see [`montgomery_ladder`].
+pub trait MontgomeryCurve {
+    /// Returns the prime modulus \( p \) of the field
+    fn prime_modulus(&self) -> BigUint;
+
+    /// Returns the coefficient \( A \)
+    fn a(&self) -> BigUint;
+
+    /// Returns the coefficient \( B \)
+    fn b(&self) -> BigUint;
+
+    /// Returns the u-coordinate of the base point
+    fn base_point_u(&self) -> BigUint;
+
+    /// Checks whether \( (x, y) \) satisfies \( By^2 = x^3 + Ax^2 + x \mod p \).
+    fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        let p = self.prime_modulus();
+        if *x >= p || *y >= p {
+            return false;
+        }
+
+        let lhs = (self.b() * y * y) % &p;
+        let rhs = (x * x * x + self.a() * x * x + x) % &p;
+        lhs == rhs
+    }
+}
+
+/// Curve25519: \( y^2 = x^3 + 486662x^2 + x \) over \( \mathbb{F}_{2^{255} - 19} \) (RFC 7748)
+pub struct Curve25519;
+
+impl MontgomeryCurve for Curve25519 {
+    fn prime_modulus(&self) -> BigUint {
+        (BigUint::from(1_u8) << 255) - BigUint::from(19_u8)
+    }
+
+    fn a(&self) -> BigUint {
+        BigUint::from(486662_u32)
+    }
+
+    fn b(&self) -> BigUint {
+        BigUint::from(1_u8)
+    }
+
+    fn base_point_u(&self) -> BigUint {
+        BigUint::from(9_u8)
+    }
+}
+
+/// Computes the u-coordinate of \( kP \) from the u-coordinate of \( P \) with the Montgomery ladder.
+///
+/// The ladder keeps \( (R_0, R_1) = (mP, (m+1)P) \) in projective \( (X : Z) \) coordinates and processes
+/// the lowest `bits` bits of `scalar` from the most significant one down, following RFC 7748, section 5.
+///
+/// # Returns
+/// - The affine u-coordinate of \( kP \), which is `0` if \( kP \) is the point at infinity.
+pub fn montgomery_ladder<T: MontgomeryCurve>(
+    scalar: &BigUint,
+    u: &BigUint,
+    bits: u64,
+    curve: &T,
+) -> BigUint {
+    let p = curve.prime_modulus();
+    // (A - 2) / 4
+    let a24 = ((curve.a() + &p - BigUint::from(2_u8)) * mod_inv(BigUint::from(4_u8), &p)) % &p;
+    let sub = |a: &BigUint, b: &BigUint| (a + &p - b) % &p;
+    // Every coordinate is reduced, so the swaps can use one width that doesn't depend on the scalar
+    let width = p.bits().div_ceil(8) as usize;
+
+    let x1 = u % &p;
+    let (mut x2, mut z2) = (BigUint::from(1_u8), BigUint::ZERO);
+    let (mut x3, mut z3) = (x1.clone(), BigUint::from(1_u8));
+    let mut swap = false;
+
+    for i in (0..bits).rev() {
+        let bit = scalar.bit(i);
+        cswap_uint(swap ^ bit, &mut x2, &mut x3, width);
+        cswap_uint(swap ^ bit, &mut z2, &mut z3, width);
+        swap = bit;
+
+        let a = (&x2 + &z2) % &p;
+        let aa = (&a * &a) % &p;
+        let b = sub(&x2, &z2);
+        let bb = (&b * &b) % &p;
+        let e = sub(&aa, &bb);
+        let c = (&x3 + &z3) % &p;
+        let d = sub(&x3, &z3);
+        let da = (d * a) % &p;
+        let cb = (c * b) % &p;
+
+        let sum = (&da + &cb) % &p;
+        x3 = (&sum * &sum) % &p;
+        let difference = sub(&da, &cb);
+        z3 = (&x1 * &difference * &difference) % &p;
+        x2 = (&aa * &bb) % &p;
+        z2 = (&e * (aa + &a24 * &e)) % &p;
+    }
+    cswap_uint(swap, &mut x2, &mut x3, width);
+    cswap_uint(swap, &mut z2, &mut z3, width);
+
+    // 0^(p-2) = 0, so the point at infinity maps to u = 0
+    (x2 * mod_inv(z2, &p)) % &p
+}
+
+/// The X25519 function of RFC 7748: multiplies the Curve25519 point with u-coordinate `u_coordinate` by `scalar`.
+///
+/// Both inputs and the output are 32-byte little-endian strings. The scalar is clamped (the lowest three
+/// bits and the top bit cleared, bit 254 set) and the top bit of the u-coordinate is ignored.
+pub fn x25519(scalar: &[u8; 32], u_coordinate: &[u8; 32]) -> [u8; 32] {
+    let mut k = *scalar;
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+
+    let mut u = *u_coordinate;
+    u[31] &= 127;
+
+    let result = montgomery_ladder(
+        &BigUint::from_bytes_le(&k),
+        &BigUint::from_bytes_le(&u),
+        255,
+        &Curve25519,
+    );
+
+    let mut output = [0_u8; 32];
+    let bytes = result.to_bytes_le();
+    output[..bytes.len()].copy_from_slice(&bytes);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::{montgomery_ladder, x25519, Curve25519, MontgomeryCurve};
+
+    fn bytes32(hex: &str) -> [u8; 32] {
+        let mut bytes = [0_u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_x25519_rfc7748_vector() {
+        // RFC 7748, section 5.2
+        let scalar = bytes32("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+        let u = bytes32("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c");
+        let expected = bytes32("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552");
+
+        assert_eq!(x25519(&scalar, &u), expected);
+    }
+
+    #[test]
+    fn test_x25519_shared_secret() {
+        // RFC 7748, section 6.1
+        let mut base = [0_u8; 32];
+        base[0] = 9;
+        let alice = bytes32("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob = bytes32("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+
+        let alice_public = x25519(&alice, &base);
+        let bob_public = x25519(&bob, &base);
+        assert_eq!(
+            alice_public,
+            bytes32("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public,
+            bytes32("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+
+        let shared = bytes32("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!(x25519(&alice, &bob_public), shared);
+        assert_eq!(x25519(&bob, &alice_public), shared);
+    }
+
+    #[test]
+    fn test_ladder_at_group_order() {
+        // The base point has prime order 2^252 + 27742317777372353535851937790883648493 (RFC 7748, section 4.1)
+        let order = (BigUint::from(1_u8) << 252)
+            + BigUint::parse_bytes(b"27742317777372353535851937790883648493", 10).unwrap();
+        let u = Curve25519.base_point_u();
+
+        assert_eq!(
+            montgomery_ladder(&order, &u, 253, &Curve25519),
+            BigUint::ZERO
+        );
+        assert_eq!(montgomery_ladder(&(&order + 1_u8), &u, 253, &Curve25519), u);
+    }
+
+    #[test]
+    fn test_base_point_on_curve() {
+        // RFC 7748, section 4.1
+        let v = BigUint::parse_bytes(
+            b"14781619447589544791020593568409986887264606134616475288964881837755586237401",
+            10,
+        )
+        .unwrap();
+
+        assert!(Curve25519.is_on_curve(&Curve25519.base_point_u(), &v));
+        assert!(!Curve25519.is_on_curve(&Curve25519.base_point_u(), &(v + 1_u8)));
+    }
+}
@@ -0,0 +1,206 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num_bigint::BigUint;
+
+use super::{
+    arithmetic::{add_two_points, negate_point},
+    curve::{Curve, CurvePoint},
+};
+
+/// A point borrowed together with its curve, so that the group law can be written with operators.
+///
+/// `p + q`, `p - q` and `-p` are shorthands for [`add_two_points`] and [`negate_point`], and `k * p` for
+/// [`Curve::scalar_mul`] with a `BigUint` scalar on the left. Binary operators between two points use the curve
+/// of the left operand.
+///
+/// # Examples
+/// ```rust
+/// use num_bigint::BigUint;
+/// use signatures::elliptic_curves::curve::{Curve, CurvePoint};
+/// use signatures::elliptic_curves::ops::PointWithCurve;
+/// use signatures::elliptic_curves::secp256k1::Secp256k1;
+///
+/// let g = PointWithCurve::new(Secp256k1.generator_point(), &Secp256k1);
+/// assert_eq!((g.clone() - g.clone()).into_point(), CurvePoint::Infinity);
+///
+/// // 2G + 3G = 5G
+/// let (two, three) = (BigUint::from(2_u8), BigUint::from(3_u8));
+/// assert_eq!(
+///     (two * g.clone() + three * g).into_point(),
+///     Secp256k1.calculate_public_key(BigUint::from(5_u8))
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct PointWithCurve<'a, T: Curve> {
+    point: CurvePoint,
+    curve: &'a T,
+}
+
+impl<'a, T: Curve> PointWithCurve<'a, T> {
+    /// Pairs `point` with `curve`, without checking that it lies on it
+    pub fn new(point: CurvePoint, curve: &'a T) -> Self {
+        Self { point, curve }
+    }
+
+    /// Returns the point
+    pub fn point(&self) -> &CurvePoint {
+        &self.point
+    }
+
+    /// Returns the curve
+    pub fn curve(&self) -> &'a T {
+        self.curve
+    }
+
+    /// Consumes the wrapper and returns the point
+    pub fn into_point(self) -> CurvePoint {
+        self.point
+    }
+}
+
+impl<T: Curve> PartialEq for PointWithCurve<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<'a, T: Curve> Add for PointWithCurve<'a, T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            add_two_points(self.point, other.point, self.curve),
+            self.curve,
+        )
+    }
+}
+
+impl<'a, T: Curve> Neg for PointWithCurve<'a, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(negate_point(self.point, self.curve), self.curve)
+    }
+}
+
+impl<'a, T: Curve> Sub for PointWithCurve<'a, T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<'a, T: Curve> Mul<PointWithCurve<'a, T>> for &BigUint {
+    type Output = PointWithCurve<'a, T>;
+
+    fn mul(self, point: PointWithCurve<'a, T>) -> PointWithCurve<'a, T> {
+        PointWithCurve::new(point.curve.scalar_mul(self, point.point), point.curve)
+    }
+}
+
+impl<'a, T: Curve> Mul<PointWithCurve<'a, T>> for BigUint {
+    type Output = PointWithCurve<'a, T>;
+
+    fn mul(self, point: PointWithCurve<'a, T>) -> PointWithCurve<'a, T> {
+        &self * point
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::PointWithCurve;
+    use crate::elliptic_curves::{
+        arithmetic::{add_two_points, negate_point},
+        curve::{Curve, CurvePoint},
+        p256::P256,
+        secp256k1::Secp256k1,
+    };
+
+    #[test]
+    fn test_operators_match_functions() {
+        fn check<T: Curve>(curve: T) {
+            let p = curve.calculate_public_key(BigUint::from(3_u8));
+            let q = curve.calculate_public_key(BigUint::from(5_u8));
+            let wrap = |point: &CurvePoint| PointWithCurve::new(point.clone(), &curve);
+
+            assert_eq!(
+                (wrap(&p) + wrap(&q)).into_point(),
+                add_two_points(p.clone(), q.clone(), &curve)
+            );
+            assert_eq!(
+                (wrap(&p) + wrap(&p)).into_point(),
+                add_two_points(p.clone(), p.clone(), &curve)
+            );
+            assert_eq!((-wrap(&p)).into_point(), negate_point(p.clone(), &curve));
+            assert_eq!(
+                (wrap(&p) - wrap(&q)).into_point(),
+                add_two_points(p.clone(), negate_point(q.clone(), &curve), &curve)
+            );
+            // 5G - 3G = 2G
+            assert_eq!(
+                (wrap(&q) - wrap(&p)).into_point(),
+                curve.calculate_public_key(BigUint::from(2_u8))
+            );
+        }
+
+        check(Secp256k1);
+        check(P256);
+    }
+
+    #[test]
+    fn test_operators_with_infinity() {
+        let g = Secp256k1.generator_point();
+        let wrap = |point: CurvePoint| PointWithCurve::new(point, &Secp256k1);
+
+        assert_eq!(
+            wrap(g.clone()) + wrap(CurvePoint::Infinity),
+            wrap(g.clone())
+        );
+        assert_eq!(
+            wrap(g.clone()) - wrap(g.clone()),
+            wrap(CurvePoint::Infinity)
+        );
+        assert_eq!(-wrap(CurvePoint::Infinity), wrap(CurvePoint::Infinity));
+    }
+
+    #[test]
+    fn test_scalar_mul_operator_matches_scalar_mul() {
+        let curve = Secp256k1;
+        let point = curve.calculate_public_key(BigUint::from(7_u8));
+        let scalars = [
+            BigUint::ZERO,
+            BigUint::from(1_u8),
+            BigUint::from(0xdead_beef_u32),
+            curve.order() - 1_u8,
+            curve.order(),
+        ];
+
+        for scalar in scalars {
+            let expected = curve.scalar_mul(&scalar, point.clone());
+            let wrapped = PointWithCurve::new(point.clone(), &curve);
+            assert_eq!((&scalar * wrapped.clone()).into_point(), expected);
+            assert_eq!((scalar * wrapped).into_point(), expected);
+        }
+    }
+
+    #[test]
+    fn test_linear_combination() {
+        // u1 G + u2 Q with Q = dG equals (u1 + u2 d) G
+        let curve = P256;
+        let (u1, u2, d) = (
+            BigUint::from(11_u8),
+            BigUint::from(13_u8),
+            BigUint::from(17_u8),
+        );
+        let g = PointWithCurve::new(curve.generator_point(), &curve);
+        let q = PointWithCurve::new(curve.calculate_public_key(d.clone()), &curve);
+
+        assert_eq!(
+            (u1.clone() * g + u2.clone() * q).into_point(),
+            curve.calculate_public_key(u1 + u2 * d)
+        );
+    }
+}
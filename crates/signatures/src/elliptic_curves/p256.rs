@@ -0,0 +1,124 @@
+use num_bigint::BigUint;
+
+use super::curve::{Curve, CurvePoint};
+
+/// Implementation of the NIST P-256 (secp256r1) elliptic curve
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct P256;
+
+impl Curve for P256 {
+    fn generator_point(&self) -> CurvePoint {
+        CurvePoint::Affine {
+            x: BigUint::parse_bytes(
+                b"6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+                16,
+            )
+            .unwrap(),
+            y: BigUint::parse_bytes(
+                b"4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5",
+                16,
+            )
+            .unwrap(),
+        }
+    }
+
+    fn prime_modulus(&self) -> BigUint {
+        BigUint::parse_bytes(
+            b"FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF",
+            16,
+        )
+        .unwrap()
+    }
+
+    /// \( a = -3 \mod p \)
+    fn a(&self) -> BigUint {
+        self.prime_modulus() - BigUint::from(3_u32)
+    }
+
+    fn b(&self) -> BigUint {
+        BigUint::parse_bytes(
+            b"5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B",
+            16,
+        )
+        .unwrap()
+    }
+
+    fn order(&self) -> BigUint {
+        BigUint::parse_bytes(
+            b"FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551",
+            16,
+        )
+        .unwrap()
+    }
+
+    fn identity(&self) -> CurvePoint {
+        CurvePoint::Infinity
+    }
+
+    /// 1.2.840.10045.3.1.7
+    fn oid(&self) -> Option<&'static [u8]> {
+        Some(&[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07])
+    }
+
+    fn name(&self) -> &'static str {
+        "P-256"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::{Curve, CurvePoint, P256};
+    use crate::elliptic_curves::{
+        arithmetic::add_two_points,
+        jacobian::{to_affine, to_jacobian},
+    };
+
+    #[test]
+    fn should_have_valid_parameters() {
+        assert_eq!(P256.validate(), Ok(()));
+    }
+
+    #[test]
+    fn generator_should_be_on_the_curve() {
+        let curve = P256;
+        assert!(curve.is_on_curve(&curve.generator_point()));
+    }
+
+    #[test]
+    fn should_double_with_nonzero_a() {
+        // secp256k1 has a = 0, so only a curve like P-256 exercises the a-term of the tangent slope
+        let curve = P256;
+        assert_ne!(curve.a(), BigUint::ZERO);
+
+        let generator = curve.generator_point();
+        let expected = CurvePoint::Affine {
+            x: BigUint::parse_bytes(
+                b"7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+                16,
+            )
+            .unwrap(),
+            y: BigUint::parse_bytes(
+                b"07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1",
+                16,
+            )
+            .unwrap(),
+        };
+
+        assert_eq!(
+            add_two_points(generator.clone(), generator.clone(), &curve),
+            expected
+        );
+        assert_eq!(
+            to_affine(&to_jacobian(&generator).double(&curve), &curve),
+            expected
+        );
+        assert_eq!(curve.scalar_mul(&BigUint::from(2_u8), generator), expected);
+    }
+
+    #[test]
+    fn should_provide_128_bit_security() {
+        assert_eq!(P256.security_bits(), 128);
+    }
+}
@@ -0,0 +1,153 @@
+use alloc::boxed::Box;
+
+use num_bigint::BigUint;
+use once_cell::race::OnceBox;
+
+use super::curve::{Curve, CurvePoint};
+
+/// Big-endian curve constants from FIPS 186-4, section D.1.2.4
+const GENERATOR_X_BYTES: [u8; 48] = [
+    0xaa, 0x87, 0xca, 0x22, 0xbe, 0x8b, 0x05, 0x37, 0x8e, 0xb1, 0xc7, 0x1e, 0xf3, 0x20, 0xad, 0x74,
+    0x6e, 0x1d, 0x3b, 0x62, 0x8b, 0xa7, 0x9b, 0x98, 0x59, 0xf7, 0x41, 0xe0, 0x82, 0x54, 0x2a, 0x38,
+    0x55, 0x02, 0xf2, 0x5d, 0xbf, 0x55, 0x29, 0x6c, 0x3a, 0x54, 0x5e, 0x38, 0x72, 0x76, 0x0a, 0xb7,
+];
+const GENERATOR_Y_BYTES: [u8; 48] = [
+    0x36, 0x17, 0xde, 0x4a, 0x96, 0x26, 0x2c, 0x6f, 0x5d, 0x9e, 0x98, 0xbf, 0x92, 0x92, 0xdc, 0x29,
+    0xf8, 0xf4, 0x1d, 0xbd, 0x28, 0x9a, 0x14, 0x7c, 0xe9, 0xda, 0x31, 0x13, 0xb5, 0xf0, 0xb8, 0xc0,
+    0x0a, 0x60, 0xb1, 0xce, 0x1d, 0x7e, 0x81, 0x9d, 0x7a, 0x43, 0x1d, 0x7c, 0x90, 0xea, 0x0e, 0x5f,
+];
+const PRIME_MODULUS_BYTES: [u8; 48] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+];
+const B_BYTES: [u8; 48] = [
+    0xb3, 0x31, 0x2f, 0xa7, 0xe2, 0x3e, 0xe7, 0xe4, 0x98, 0x8e, 0x05, 0x6b, 0xe3, 0xf8, 0x2d, 0x19,
+    0x18, 0x1d, 0x9c, 0x6e, 0xfe, 0x81, 0x41, 0x12, 0x03, 0x14, 0x08, 0x8f, 0x50, 0x13, 0x87, 0x5a,
+    0xc6, 0x56, 0x39, 0x8d, 0x8a, 0x2e, 0xd1, 0x9d, 0x2a, 0x85, 0xc8, 0xed, 0xd3, 0xec, 0x2a, 0xef,
+];
+const ORDER_BYTES: [u8; 48] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc7, 0x63, 0x4d, 0x81, 0xf4, 0x37, 0x2d, 0xdf,
+    0x58, 0x1a, 0x0d, 0xb2, 0x48, 0xb0, 0xa7, 0x7a, 0xec, 0xec, 0x19, 0x6a, 0xcc, 0xc5, 0x29, 0x73,
+];
+
+/// Curve constants, built from the bytes on first use
+static GENERATOR: OnceBox<CurvePoint> = OnceBox::new();
+static PRIME_MODULUS: OnceBox<BigUint> = OnceBox::new();
+static B: OnceBox<BigUint> = OnceBox::new();
+static ORDER: OnceBox<BigUint> = OnceBox::new();
+
+/// Implementation of the NIST P-384 (secp384r1) elliptic curve
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct P384;
+
+impl Curve for P384 {
+    fn generator_point(&self) -> CurvePoint {
+        GENERATOR
+            .get_or_init(|| {
+                Box::new(CurvePoint::Affine {
+                    x: BigUint::from_bytes_be(&GENERATOR_X_BYTES),
+                    y: BigUint::from_bytes_be(&GENERATOR_Y_BYTES),
+                })
+            })
+            .clone()
+    }
+
+    fn prime_modulus(&self) -> BigUint {
+        PRIME_MODULUS
+            .get_or_init(|| Box::new(BigUint::from_bytes_be(&PRIME_MODULUS_BYTES)))
+            .clone()
+    }
+
+    /// \( a = -3 \mod p \)
+    fn a(&self) -> BigUint {
+        self.prime_modulus() - BigUint::from(3_u32)
+    }
+
+    fn b(&self) -> BigUint {
+        B.get_or_init(|| Box::new(BigUint::from_bytes_be(&B_BYTES)))
+            .clone()
+    }
+
+    fn order(&self) -> BigUint {
+        ORDER
+            .get_or_init(|| Box::new(BigUint::from_bytes_be(&ORDER_BYTES)))
+            .clone()
+    }
+
+    fn identity(&self) -> CurvePoint {
+        CurvePoint::Infinity
+    }
+
+    /// 1.3.132.0.34
+    fn oid(&self) -> Option<&'static [u8]> {
+        Some(&[0x2b, 0x81, 0x04, 0x00, 0x22])
+    }
+
+    fn name(&self) -> &'static str {
+        "P-384"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use sha2::Sha384;
+
+    use super::{Curve, CurvePoint, P384};
+    use crate::{digital_signature::Signature, ecdsa::verify_message};
+
+    #[test]
+    fn should_have_valid_parameters() {
+        assert_eq!(P384.validate(), Ok(()));
+    }
+
+    #[test]
+    fn generator_should_be_on_the_curve() {
+        let curve = P384;
+        assert!(curve.is_on_curve(&curve.generator_point()));
+    }
+
+    #[test]
+    fn doubling_should_match_scalar_multiplication() {
+        let curve = P384;
+        let doubled = curve.double(curve.generator_point());
+        assert_eq!(curve.calculate_public_key(BigUint::from(2_u8)), doubled);
+
+        // 2G from the NIST test vectors at http://point-at-infinity.org/ecc/nisttv
+        let hex = |value: &[u8]| BigUint::parse_bytes(value, 16).unwrap();
+        assert_eq!(
+            doubled,
+            CurvePoint::Affine {
+                x: hex(b"08D999057BA3D2D969260045C55B97F089025959A6F434D651D207D19FB96E9E4FE0E86EBE0E64F85B96A9C75295DF61"),
+                y: hex(b"8E80F1FA5B1B3CEDB7BFE8DFFD6DBA74B275D875BC6CC43E904E505F256AB4255FFD43E94D39E22D61501E700A940E80"),
+            }
+        );
+    }
+
+    #[test]
+    fn should_use_48_byte_field_elements_and_scalars() {
+        assert_eq!(P384.field_byte_size(), 48);
+        assert_eq!(P384.order_bits(), 384);
+        assert_eq!(P384.order_byte_size(), 48);
+    }
+
+    #[test]
+    fn should_provide_192_bit_security() {
+        assert_eq!(P384.security_bits(), 192);
+    }
+
+    #[test]
+    fn should_sign_and_verify_with_sha384() {
+        let signer = Signature::from_secret_hex(P384, "c0ffee").unwrap();
+        let signature = signer.sign_message::<Sha384>(b"hello");
+
+        assert_eq!(signature.to_compact(&P384).len(), 96);
+        assert!(verify_message::<Sha384, _>(
+            b"hello",
+            &signature,
+            &signer.to_public_key().unwrap()
+        ));
+    }
+}
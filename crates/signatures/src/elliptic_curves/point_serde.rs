@@ -0,0 +1,212 @@
+//! Serde support for [`CurvePoint`], enabled by the `serde` feature.
+//!
+//! A field whose curve is known should use [`serialize_sec1`] and [`deserialize_sec1`], which write the
+//! compressed SEC1 encoding as hex and check the point is on the curve when reading it back:
+//!
+//! ```ignore
+//! use serde::{Deserialize, Serialize};
+//! use signatures::elliptic_curves::{curve::CurvePoint, point_serde, secp256k1::Secp256k1};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Message {
+//!     #[serde(
+//!         serialize_with = "point_serde::serialize_sec1::<Secp256k1, _>",
+//!         deserialize_with = "point_serde::deserialize_sec1::<Secp256k1, _>"
+//!     )]
+//!     public_key: CurvePoint,
+//! }
+//! ```
+//!
+//! The `Serialize` and `Deserialize` impls of `CurvePoint` itself can't know the curve, so they can neither
+//! compress a point nor pad it to the field size. They write the point at infinity as the single byte `00`
+//! and affine points as `04 || x || y`, with both coordinates left-padded to the width of the longer one.
+//! That width varies with the point, so the output is not SEC1 and is only meant to be read back by this
+//! crate.
+
+use alloc::{string::String, vec::Vec};
+
+use num_bigint::BigUint;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    curve::{Curve, CurvePoint},
+    sec1::{from_sec1, to_sec1},
+};
+
+/// Serializes `point` as the hex of its compressed SEC1 encoding on the curve `T`, `00` for infinity
+pub fn serialize_sec1<T: Curve + Default, S: Serializer>(
+    point: &CurvePoint,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex::encode(to_sec1(point, true, &T::default())))
+}
+
+/// Deserializes a point written by [`serialize_sec1`], or any other hex SEC1 encoding of a point on `T`.
+///
+/// # Errors
+/// - If the input is not hex, or any error raised by `from_sec1`.
+pub fn deserialize_sec1<'de, T: Curve + Default, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<CurvePoint, D::Error> {
+    let encoded = String::deserialize(deserializer)?;
+    let bytes = hex::decode(&encoded).map_err(de::Error::custom)?;
+    from_sec1(&bytes, &T::default()).map_err(de::Error::custom)
+}
+
+impl Serialize for CurvePoint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = match self {
+            CurvePoint::Infinity => Vec::from([0x00]),
+            CurvePoint::Affine { x, y } => {
+                let (x, y) = (x.to_bytes_be(), y.to_bytes_be());
+                let width = x.len().max(y.len());
+
+                let mut bytes = Vec::with_capacity(1 + 2 * width);
+                bytes.push(0x04);
+                for coordinate in [x, y] {
+                    bytes.resize(bytes.len() + width - coordinate.len(), 0);
+                    bytes.extend_from_slice(&coordinate);
+                }
+                bytes
+            }
+        };
+        serializer.serialize_str(&hex::encode(bytes))
+    }
+}
+
+impl<'de> Deserialize<'de> for CurvePoint {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        let bytes = hex::decode(&encoded).map_err(de::Error::custom)?;
+
+        match bytes.split_first() {
+            Some((0x00, [])) => Ok(CurvePoint::Infinity),
+            Some((0x04, coordinates)) if !coordinates.is_empty() && coordinates.len() % 2 == 0 => {
+                let (x, y) = coordinates.split_at(coordinates.len() / 2);
+                Ok(CurvePoint::Affine {
+                    x: BigUint::from_bytes_be(x),
+                    y: BigUint::from_bytes_be(y),
+                })
+            }
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Str(&encoded),
+                &"hex of 04 followed by two coordinates of equal width, or 00 for infinity",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String, vec::Vec};
+
+    use num_bigint::BigUint;
+
+    use super::{deserialize_sec1, serialize_sec1};
+    use crate::elliptic_curves::{
+        curve::{Curve, CurvePoint},
+        secp256k1::Secp256k1,
+    };
+
+    fn to_sec1_json<T: Curve + Default>(point: &CurvePoint) -> String {
+        let mut json = Vec::new();
+        serialize_sec1::<T, _>(point, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        String::from_utf8(json).unwrap()
+    }
+
+    fn from_sec1_json<T: Curve + Default>(json: &str) -> serde_json::Result<CurvePoint> {
+        deserialize_sec1::<T, _>(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn should_round_trip_compressed_sec1() {
+        let generator = Secp256k1.generator_point();
+        let json = to_sec1_json::<Secp256k1>(&generator);
+
+        assert_eq!(
+            json,
+            "\"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\""
+        );
+        assert_eq!(from_sec1_json::<Secp256k1>(&json).unwrap(), generator);
+
+        let infinity = to_sec1_json::<Secp256k1>(&CurvePoint::Infinity);
+        assert_eq!(infinity, "\"00\"");
+        assert_eq!(
+            from_sec1_json::<Secp256k1>(&infinity).unwrap(),
+            CurvePoint::Infinity
+        );
+    }
+
+    #[test]
+    fn should_reject_malformed_sec1() {
+        let json = to_sec1_json::<Secp256k1>(&Secp256k1.generator_point());
+        let uncompressed_prefix = json.replacen("02", "04", 1);
+        // (1, 1) in uncompressed form, which is not on the curve
+        let off_curve = format!("\"04{:064x}{:064x}\"", 1, 1);
+
+        for json in ["\"zz\"", "\"\"", &uncompressed_prefix, &off_curve] {
+            assert!(
+                from_sec1_json::<Secp256k1>(json).is_err(),
+                "{json} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn should_round_trip_generator() {
+        let generator = Secp256k1.generator_point();
+        let json = serde_json::to_string(&generator).unwrap();
+
+        assert_eq!(
+            json,
+            "\"0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8\""
+        );
+        assert_eq!(
+            serde_json::from_str::<CurvePoint>(&json).unwrap(),
+            generator
+        );
+    }
+
+    #[test]
+    fn should_pad_short_coordinates() {
+        let point = CurvePoint::Affine {
+            x: BigUint::from(1_u8),
+            y: BigUint::from(0x0203_u16),
+        };
+        let json = serde_json::to_string(&point).unwrap();
+
+        assert_eq!(json, "\"0400010203\"");
+        assert_eq!(serde_json::from_str::<CurvePoint>(&json).unwrap(), point);
+    }
+
+    #[test]
+    fn should_round_trip_infinity() {
+        let json = serde_json::to_string(&CurvePoint::Infinity).unwrap();
+
+        assert_eq!(json, "\"00\"");
+        assert_eq!(
+            serde_json::from_str::<CurvePoint>(&json).unwrap(),
+            CurvePoint::Infinity
+        );
+    }
+
+    #[test]
+    fn should_reject_malformed_input() {
+        for json in [
+            "\"zz\"",
+            "\"040\"",
+            "\"04\"",
+            "\"0401020\"",
+            "\"040102030\"",
+            "\"02010203\"",
+            "\"0000\"",
+            "\"\"",
+            "42",
+        ] {
+            assert!(
+                serde_json::from_str::<CurvePoint>(json).is_err(),
+                "{json} should be rejected"
+            );
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use num_bigint::BigUint;
+
+use super::{
+    arithmetic::{mod_add, mod_mul, try_mod_inv},
+    curve::Curve,
+    sec1::to_padded_bytes,
+};
+use crate::error::Error;
+
+/// An integer modulo the order \( n \) of the curve `C`, always kept in \( [0, n) \).
+///
+/// The curve is a type parameter rather than a field so that conversions like `Scalar::<Secp256k1>::from(7)`
+/// don't need a curve value, which is why `C` has to implement `Default`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scalar<C: Curve + Default> {
+    value: BigUint,
+    curve: PhantomData<C>,
+}
+
+impl<C: Curve + Default> Scalar<C> {
+    /// Returns the value as an integer in \( [0, n) \)
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    /// Consumes the scalar and returns its value
+    pub fn into_inner(self) -> BigUint {
+        self.value
+    }
+
+    /// Check if the scalar is zero
+    pub fn is_zero(&self) -> bool {
+        self.value == BigUint::ZERO
+    }
+}
+
+/// Reduces the integer modulo the order, which is a no-op for every curve with an order above \( 2^{64} \)
+impl<C: Curve + Default> From<u64> for Scalar<C> {
+    fn from(value: u64) -> Self {
+        Self {
+            value: BigUint::from(value) % C::default().order(),
+            curve: PhantomData,
+        }
+    }
+}
+
+/// Parses a big-endian integer.
+///
+/// # Errors
+/// - `Error::ScalarOutOfRange` if the value is not below the order. Unlike `From<u64>`, larger inputs are
+///   rejected instead of reduced, since silently reducing a 32-byte secret would hide a malformed input.
+impl<C: Curve + Default> TryFrom<&[u8]> for Scalar<C> {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let value = BigUint::from_bytes_be(bytes);
+        if value >= C::default().order() {
+            return Err(Error::ScalarOutOfRange);
+        }
+
+        Ok(Self {
+            value,
+            curve: PhantomData,
+        })
+    }
+}
+
+impl<C: Curve + Default> From<Scalar<C>> for BigUint {
+    fn from(scalar: Scalar<C>) -> Self {
+        scalar.value
+    }
+}
+
+/// Computes \( (a + b) \mod n \), where \( n \) is the order of `curve`.
+///
+/// The helpers in this module work modulo the group order, as needed for nonces, secret keys and signature
+/// components. Coordinates live modulo the prime \( p \) instead, see the `arithmetic` module.
+pub fn add_mod_order<T: Curve>(a: &BigUint, b: &BigUint, curve: &T) -> BigUint {
+    mod_add(a, b, &curve.order())
+}
+
+/// Computes \( (a \cdot b) \mod n \), where \( n \) is the order of `curve`.
+pub fn mul_mod_order<T: Curve>(a: &BigUint, b: &BigUint, curve: &T) -> BigUint {
+    mod_mul(a, b, &curve.order())
+}
+
+/// Computes \( a^{-1} \mod n \), where \( n \) is the (prime) order of `curve`.
+///
+/// # Errors
+/// - `Error::NotInvertible` if `value` is a multiple of the order.
+pub fn inv_mod_order<T: Curve>(value: &BigUint, curve: &T) -> Result<BigUint, Error> {
+    try_mod_inv(value, &curve.order())
+}
+
+/// Encodes a scalar as big-endian bytes, left-padded to the byte length of the order.
+///
+/// Unlike `BigUint::to_bytes_be`, which drops leading zeros, the width is the same for every scalar of the
+/// curve, as fixed-width formats such as signatures and secret keys require.
+pub fn scalar_to_be_bytes<T: Curve>(value: &BigUint, curve: &T) -> Vec<u8> {
+    to_padded_bytes(value, curve.order_byte_size())
+}
+
+/// Decodes a scalar produced by [`scalar_to_be_bytes`].
+///
+/// # Errors
+/// - `Error::ScalarOutOfRange` if `bytes` is not exactly as long as the order or encodes a value not below it.
+pub fn scalar_from_be_bytes<T: Curve>(bytes: &[u8], curve: &T) -> Result<BigUint, Error> {
+    let value = BigUint::from_bytes_be(bytes);
+    if bytes.len() != curve.order_byte_size() || value >= curve.order() {
+        return Err(Error::ScalarOutOfRange);
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::{
+        add_mod_order, inv_mod_order, mul_mod_order, scalar_from_be_bytes, scalar_to_be_bytes,
+        Scalar,
+    };
+    use crate::{
+        elliptic_curves::{curve::Curve, generic::GenericCurve, secp256k1::Secp256k1},
+        error::Error,
+    };
+
+    #[test]
+    fn test_from_u64() {
+        let scalar = Scalar::<Secp256k1>::from(7);
+        assert_eq!(scalar.value(), &BigUint::from(7_u8));
+        assert!(Scalar::<Secp256k1>::from(0).is_zero());
+    }
+
+    #[test]
+    fn test_add_mod_order() {
+        let curve = GenericCurve::toy();
+        let cases: [(u8, u8, u8); 5] =
+            [(0, 0, 0), (7, 5, 12), (10, 9, 0), (18, 18, 17), (25, 1, 7)];
+        for (a, b, expected) in cases {
+            assert_eq!(
+                add_mod_order(&BigUint::from(a), &BigUint::from(b), &curve),
+                BigUint::from(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul_mod_order() {
+        let curve = GenericCurve::toy();
+        // Reduced modulo the order 19, not the field prime 17
+        let cases: [(u8, u8, u8); 5] = [(0, 5, 0), (3, 5, 15), (4, 5, 1), (18, 18, 1), (17, 2, 15)];
+        for (a, b, expected) in cases {
+            assert_eq!(
+                mul_mod_order(&BigUint::from(a), &BigUint::from(b), &curve),
+                BigUint::from(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_inv_mod_order() {
+        let curve = GenericCurve::toy();
+        for value in 1..19_u8 {
+            let inverse = inv_mod_order(&BigUint::from(value), &curve).unwrap();
+            assert_eq!(
+                mul_mod_order(&BigUint::from(value), &inverse, &curve),
+                BigUint::from(1_u8)
+            );
+        }
+        assert_eq!(
+            inv_mod_order(&BigUint::from(4_u8), &curve),
+            Ok(BigUint::from(5_u8))
+        );
+        for value in [0_u8, 19, 38] {
+            assert_eq!(
+                inv_mod_order(&BigUint::from(value), &curve),
+                Err(Error::NotInvertible)
+            );
+        }
+    }
+
+    #[test]
+    fn test_scalar_be_bytes_round_trip() {
+        let order = Secp256k1.order();
+        let cases = [
+            BigUint::ZERO,
+            BigUint::from(1_u8),
+            BigUint::from(0x0102_u16),
+            &order - 1_u8,
+        ];
+
+        for value in cases {
+            let bytes = scalar_to_be_bytes(&value, &Secp256k1);
+            assert_eq!(bytes.len(), 32);
+            assert_eq!(scalar_from_be_bytes(&bytes, &Secp256k1), Ok(value));
+        }
+
+        let mut expected = [0_u8; 32];
+        expected[30..].copy_from_slice(&[0x01, 0x02]);
+        assert_eq!(
+            scalar_to_be_bytes(&BigUint::from(0x0102_u16), &Secp256k1),
+            expected
+        );
+        // The order 19 of the small curve fits in a single byte
+        assert_eq!(
+            scalar_to_be_bytes(&BigUint::from(7_u8), &GenericCurve::toy()),
+            [7]
+        );
+    }
+
+    #[test]
+    fn test_scalar_from_be_bytes_rejects_malformed_input() {
+        let order = Secp256k1.order();
+        let cases = [
+            Vec::new(),
+            vec![0x01],
+            vec![0; 33],
+            order.to_bytes_be(),
+            vec![0xff; 32],
+        ];
+
+        for bytes in cases {
+            assert_eq!(
+                scalar_from_be_bytes(&bytes, &Secp256k1),
+                Err(Error::ScalarOutOfRange)
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_from_in_range_bytes() {
+        let max = Secp256k1.order() - 1_u8;
+        let cases = [
+            (Vec::new(), BigUint::ZERO),
+            (vec![0x01], BigUint::from(1_u8)),
+            (vec![0x00, 0x00, 0x01, 0x00], BigUint::from(256_u16)),
+            (max.to_bytes_be(), max),
+        ];
+
+        for (bytes, expected) in cases {
+            let scalar = Scalar::<Secp256k1>::try_from(bytes.as_slice()).unwrap();
+            assert_eq!(BigUint::from(scalar), expected);
+        }
+    }
+
+    #[test]
+    fn test_try_from_out_of_range_bytes() {
+        let order = Secp256k1.order();
+        for value in [order.clone(), order + 1_u8, BigUint::from(1_u8) << 256] {
+            assert_eq!(
+                Scalar::<Secp256k1>::try_from(value.to_bytes_be().as_slice()),
+                Err(Error::ScalarOutOfRange)
+            );
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+
+use super::curve::{Curve, CurvePoint};
+use crate::error::Error;
+
+/// Encodes a point in the SEC1 format.
+///
+/// - The point at infinity is the single byte `0x00`.
+/// - Compressed points are `0x02 || x` for an even `y` and `0x03 || x` for an odd `y`.
+/// - Uncompressed points are `0x04 || x || y`.
+///
+/// Coordinates are big-endian and left-padded to the curve's `field_byte_size`.
+pub fn to_sec1<T: Curve>(point: &CurvePoint, compressed: bool, curve: &T) -> Vec<u8> {
+    let CurvePoint::Affine { x, y } = point else {
+        return Vec::from([0x00]);
+    };
+    let size = curve.field_byte_size();
+
+    let mut bytes = Vec::with_capacity(1 + 2 * size);
+    if compressed {
+        bytes.push(if y.bit(0) { 0x03 } else { 0x02 });
+        bytes.extend_from_slice(&to_padded_bytes(x, size));
+    } else {
+        bytes.push(0x04);
+        bytes.extend_from_slice(&to_padded_bytes(x, size));
+        bytes.extend_from_slice(&to_padded_bytes(y, size));
+    }
+    bytes
+}
+
+/// Decodes a SEC1 encoded point, decompressing it if necessary.
+///
+/// # Errors
+/// - `Error::InvalidEncoding` if the prefix is unknown or the length doesn't match it.
+/// - `Error::PointNotOnCurve` if the coordinates don't describe a point on the curve.
+pub fn from_sec1<T: Curve>(bytes: &[u8], curve: &T) -> Result<CurvePoint, Error> {
+    let size = curve.field_byte_size();
+
+    match bytes.split_first() {
+        Some((0x00, [])) => Ok(CurvePoint::Infinity),
+        Some((&prefix @ (0x02 | 0x03), x)) if x.len() == size => {
+            // The prefix gives the parity of y
+            curve
+                .lift_x(&BigUint::from_bytes_be(x), prefix == 0x02)
+                .ok_or(Error::PointNotOnCurve)
+        }
+        Some((0x04, coordinates)) if coordinates.len() == 2 * size => {
+            let (x, y) = coordinates.split_at(size);
+            let point = CurvePoint::Affine {
+                x: BigUint::from_bytes_be(x),
+                y: BigUint::from_bytes_be(y),
+            };
+            if !curve.is_on_curve(&point) {
+                return Err(Error::PointNotOnCurve);
+            }
+            Ok(point)
+        }
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+/// Serializes a value as big-endian bytes, left-padded with zeros to `size` bytes
+pub(crate) fn to_padded_bytes(value: &BigUint, size: usize) -> Vec<u8> {
+    let bytes = value.to_bytes_be();
+    let mut padded = Vec::with_capacity(size.max(bytes.len()));
+    padded.resize(size.saturating_sub(bytes.len()), 0);
+    padded.extend_from_slice(&bytes);
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::{from_sec1, to_sec1};
+    use crate::{
+        elliptic_curves::{
+            arithmetic::negate_point,
+            curve::{Curve, CurvePoint},
+            generic::GenericCurve,
+            p256::P256,
+            secp256k1::Secp256k1,
+        },
+        error::Error,
+    };
+
+    /// y^2 = x^3 + x + 6 (mod 7)
+    fn test_curve() -> GenericCurve {
+        let small = |value: u8| BigUint::from(value);
+        GenericCurve::new(
+            small(7),
+            small(1),
+            small(6),
+            small(2),
+            small(3),
+            small(11),
+            small(1),
+        )
+    }
+
+    #[test]
+    fn test_round_trip_for_every_point() {
+        let curve = test_curve();
+
+        for x in 0..7u8 {
+            for y in 0..7u8 {
+                let point = CurvePoint::Affine {
+                    x: BigUint::from(x),
+                    y: BigUint::from(y),
+                };
+                if !curve.is_on_curve(&point) {
+                    assert_eq!(
+                        from_sec1(&[0x04, x, y], &curve),
+                        Err(Error::PointNotOnCurve)
+                    );
+                    continue;
+                }
+
+                for compressed in [true, false] {
+                    let encoded = to_sec1(&point, compressed, &curve);
+                    assert_eq!(encoded.len(), if compressed { 2 } else { 3 });
+                    assert_eq!(from_sec1(&encoded, &curve), Ok(point.clone()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_point_at_infinity() {
+        for compressed in [true, false] {
+            assert_eq!(
+                to_sec1(&CurvePoint::Infinity, compressed, &test_curve()),
+                [0x00]
+            );
+            assert_eq!(
+                to_sec1(&CurvePoint::Infinity, compressed, &Secp256k1),
+                [0x00]
+            );
+        }
+        assert_eq!(from_sec1(&[0x00], &test_curve()), Ok(CurvePoint::Infinity));
+        assert_eq!(from_sec1(&[0x00], &Secp256k1), Ok(CurvePoint::Infinity));
+
+        // Only the single byte encodes infinity, not a zero-padded coordinate
+        assert_eq!(
+            from_sec1(&[0x00; 33], &Secp256k1),
+            Err(Error::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_encodings() {
+        let curve = test_curve();
+
+        for bytes in [
+            &[][..],
+            &[0x00, 0x00],
+            &[0x01, 0x02],
+            &[0x02],
+            &[0x02, 0x02, 0x03],
+            &[0x04, 0x02],
+            &[0x05, 0x02, 0x03],
+        ] {
+            assert_eq!(
+                from_sec1(bytes, &curve),
+                Err(Error::InvalidEncoding),
+                "{bytes:?}"
+            );
+        }
+        // x = 0 gives y^2 = 6, which has no square root mod 7
+        assert_eq!(
+            from_sec1(&[0x02, 0x00], &curve),
+            Err(Error::PointNotOnCurve)
+        );
+        // x must be reduced
+        assert_eq!(
+            from_sec1(&[0x02, 0x09], &curve),
+            Err(Error::PointNotOnCurve)
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_generator() {
+        let curve = Secp256k1;
+        let generator = curve.generator_point();
+
+        let compressed = to_sec1(&generator, true, &curve);
+        assert_eq!(
+            compressed,
+            BigUint::parse_bytes(
+                b"0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+                16
+            )
+            .unwrap()
+            .to_bytes_be()
+        );
+        assert_eq!(from_sec1(&compressed, &curve), Ok(generator.clone()));
+
+        let uncompressed = to_sec1(&generator, false, &curve);
+        assert_eq!(uncompressed.len(), 65);
+        assert_eq!(uncompressed[0], 0x04);
+        assert_eq!(from_sec1(&uncompressed, &curve), Ok(generator));
+    }
+
+    #[test]
+    fn test_odd_y_uses_prefix_03() {
+        // The P-256 generator has an odd y-coordinate, ending in 0xf5
+        let curve = P256;
+        let generator = curve.generator_point();
+        let CurvePoint::Affine { y, .. } = &generator else {
+            unreachable!()
+        };
+        assert!(y.bit(0));
+
+        let compressed = to_sec1(&generator, true, &curve);
+        assert_eq!(
+            compressed,
+            BigUint::parse_bytes(
+                b"036B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+                16
+            )
+            .unwrap()
+            .to_bytes_be()
+        );
+        assert_eq!(from_sec1(&compressed, &curve), Ok(generator.clone()));
+
+        // Flipping the prefix selects the other root, the negated generator
+        let mut flipped = compressed;
+        flipped[0] = 0x02;
+        assert_eq!(
+            from_sec1(&flipped, &curve),
+            Ok(negate_point(generator, &curve))
+        );
+    }
+
+    #[test]
+    fn test_p256_round_trip() {
+        let curve = P256;
+        let point = curve.calculate_public_key(BigUint::from(12345_u32));
+
+        for compressed in [true, false] {
+            let encoded = to_sec1(&point, compressed, &curve);
+            assert_eq!(from_sec1(&encoded, &curve), Ok(point.clone()));
+        }
+    }
+}
@@ -1,32 +1,55 @@
+use alloc::boxed::Box;
+
 use num_bigint::BigUint;
+use once_cell::race::OnceBox;
 
 use super::curve::{Curve, CurvePoint};
 
+/// Big-endian curve constants from SEC 2, section 2.4.1. Keeping them as bytes rather than hex strings
+/// means building the `BigUint`s can't fail.
+const GENERATOR_X_BYTES: [u8; 32] = [
+    0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87, 0x0b, 0x07,
+    0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16, 0xf8, 0x17, 0x98,
+];
+const GENERATOR_Y_BYTES: [u8; 32] = [
+    0x48, 0x3a, 0xda, 0x77, 0x26, 0xa3, 0xc4, 0x65, 0x5d, 0xa4, 0xfb, 0xfc, 0x0e, 0x11, 0x08, 0xa8,
+    0xfd, 0x17, 0xb4, 0x48, 0xa6, 0x85, 0x54, 0x19, 0x9c, 0x47, 0xd0, 0x8f, 0xfb, 0x10, 0xd4, 0xb8,
+];
+const PRIME_MODULUS_BYTES: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xfc, 0x2f,
+];
+const ORDER_BYTES: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Parsed curve constants, initialized on first use so the hot paths don't re-parse them
+static GENERATOR: OnceBox<CurvePoint> = OnceBox::new();
+static PRIME_MODULUS: OnceBox<BigUint> = OnceBox::new();
+static ORDER: OnceBox<BigUint> = OnceBox::new();
+static HALF_ORDER: OnceBox<BigUint> = OnceBox::new();
+
 /// Implementation of the secp256k1 elliptic curve
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Secp256k1;
 
 impl Curve for Secp256k1 {
     fn generator_point(&self) -> CurvePoint {
-        CurvePoint::Affine {
-            x: BigUint::parse_bytes(
-                b"55066263022277343669578718895168534326250603453777594175500187360389116729240",
-                16,
-            )
-            .unwrap(),
-            y: BigUint::parse_bytes(
-                b"32670510020758816978083085130507043184471273380659243275938904335757337482424",
-                16,
-            )
-            .unwrap(),
-        }
+        GENERATOR
+            .get_or_init(|| {
+                Box::new(CurvePoint::Affine {
+                    x: BigUint::from_bytes_be(&GENERATOR_X_BYTES),
+                    y: BigUint::from_bytes_be(&GENERATOR_Y_BYTES),
+                })
+            })
+            .clone()
     }
 
     fn prime_modulus(&self) -> BigUint {
-        BigUint::parse_bytes(
-            b"115792089237316195423570985008687907853269984665640564039457584007908834671663",
-            16,
-        )
-        .unwrap()
+        PRIME_MODULUS
+            .get_or_init(|| Box::new(BigUint::from_bytes_be(&PRIME_MODULUS_BYTES)))
+            .clone()
     }
 
     fn a(&self) -> BigUint {
@@ -38,23 +61,42 @@ impl Curve for Secp256k1 {
     }
 
     fn order(&self) -> BigUint {
-        BigUint::parse_bytes(
-            b"115792089237316195423570985008687907852837564279074904382605163141518161494337",
-            16,
-        )
-        .unwrap()
+        ORDER
+            .get_or_init(|| Box::new(BigUint::from_bytes_be(&ORDER_BYTES)))
+            .clone()
     }
 
     fn identity(&self) -> CurvePoint {
         CurvePoint::Infinity
     }
+
+    /// 1.3.132.0.10
+    fn oid(&self) -> Option<&'static [u8]> {
+        Some(&[0x2b, 0x81, 0x04, 0x00, 0x0a])
+    }
+
+    fn name(&self) -> &'static str {
+        "secp256k1"
+    }
+
+    fn half_order(&self) -> BigUint {
+        HALF_ORDER
+            .get_or_init(|| Box::new(self.order() >> 1))
+            .clone()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use num_bigint::BigUint;
+    use rand::{rngs::StdRng, SeedableRng};
 
-    use super::{Curve, Secp256k1};
+    use super::{Curve, CurvePoint, Secp256k1};
+
+    #[test]
+    fn should_report_its_name() {
+        assert_eq!(Secp256k1.name(), "secp256k1");
+    }
 
     #[test]
     fn should_create_new_non_zero_secret_key() {
@@ -62,6 +104,94 @@ mod tests {
         assert!(BigUint::ZERO < curve.generate_secret_key());
     }
 
+    #[test]
+    fn should_hash_to_a_point_on_the_curve() {
+        let curve = Secp256k1;
+        let point = curve.hash_to_curve(b"hello");
+
+        assert!(curve.is_on_curve(&point));
+        assert_ne!(point, curve.hash_to_curve(b"hello!"));
+    }
+
+    #[test]
+    fn cached_constants_should_match_the_published_values() {
+        let curve = Secp256k1;
+        // Repeated calls hit the cache and must keep returning the same values
+        for _ in 0..2 {
+            assert_eq!(
+                curve.prime_modulus(),
+                (BigUint::from(1_u8) << 256) - (BigUint::from(1_u8) << 32) - 977_u32
+            );
+            assert_eq!(
+                curve.order(),
+                BigUint::parse_bytes(
+                    b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+                    16
+                )
+                .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn cached_half_order_should_match_the_order() {
+        let curve = Secp256k1;
+        for _ in 0..2 {
+            assert_eq!(curve.half_order(), curve.order() >> 1);
+        }
+    }
+
+    #[test]
+    fn generator_should_satisfy_the_curve_equation() {
+        let curve = Secp256k1;
+        let p = curve.prime_modulus();
+        let CurvePoint::Affine { x, y } = curve.generator_point() else {
+            panic!("Generator point is not in affine coordinates");
+        };
+
+        // y^2 = x^3 + 7 (mod p)
+        assert_eq!(
+            y.modpow(&BigUint::from(2_u8), &p),
+            (x.modpow(&BigUint::from(3_u8), &p) + BigUint::from(7_u8)) % &p
+        );
+    }
+
+    #[test]
+    fn should_use_32_byte_field_elements() {
+        assert_eq!(Secp256k1.field_byte_size(), 32);
+    }
+
+    #[test]
+    fn should_use_256_bit_scalars() {
+        assert_eq!(Secp256k1.order_bits(), 256);
+        assert_eq!(Secp256k1.order_byte_size(), 32);
+    }
+
+    #[test]
+    fn should_provide_128_bit_security() {
+        assert_eq!(Secp256k1.security_bits(), 128);
+    }
+
+    #[test]
+    fn should_have_valid_parameters() {
+        assert_eq!(Secp256k1.validate(), Ok(()));
+    }
+
+    #[test]
+    fn should_generate_known_secret_key_from_seeded_rng() {
+        let curve = Secp256k1;
+        let secret = curve.generate_secret_key_with_rng(&mut StdRng::seed_from_u64(42));
+
+        assert_eq!(
+            secret,
+            BigUint::parse_bytes(
+                b"67e92d78fd7630b2a2ef6071de5134d18af00a133fad517d86cc7763222724a3",
+                16
+            )
+            .unwrap()
+        );
+    }
+
     #[test]
     fn secret_key_should_be_less_than_the_upper_bound() {
         let curve = Secp256k1;
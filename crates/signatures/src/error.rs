@@ -0,0 +1,228 @@
+/// Errors raised by the crate's fallible APIs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input is not a non-empty string of hexadecimal digits
+    InvalidHex,
+    /// A textual point representation could not be parsed
+    MalformedPoint,
+    /// A SEC1 point encoding has an unknown prefix or the wrong length, or a SEC1 private key is malformed
+    InvalidEncoding,
+    /// Coordinates do not satisfy the curve equation
+    PointNotOnCurve,
+    /// A public key (or a point derived from it) is the point at infinity
+    InvalidPublicKey,
+    /// The discriminant \( -16(4a^3 + 27b^2) \) is zero modulo \( p \)
+    SingularCurve,
+    /// The generator point is the point at infinity
+    GeneratorIsIdentity,
+    /// The generator point does not satisfy the curve equation
+    GeneratorNotOnCurve,
+    /// Multiplying the generator by the order does not yield the point at infinity
+    InvalidGeneratorOrder,
+    /// The point count implied by the order and cofactor violates the Hasse bound \( |p + 1 - nh| \le 2\sqrt{p} \)
+    OrderOutsideHasseBound,
+    /// The secret key is not in the range `[1, order)`
+    SecretOutOfRange,
+    /// A scalar is not in the range `[0, order)`
+    ScalarOutOfRange,
+    /// A signature has the wrong length or a component outside `[1, order)`
+    InvalidSignature,
+    /// The element is zero modulo the modulus and has no inverse
+    NotInvertible,
+    /// An ECDSA nonce was about to be reused for a different message
+    NonceReuse,
+    /// A mnemonic phrase has an unknown word or the wrong number of words, or its entropy has the wrong length
+    InvalidMnemonic,
+    /// The checksum bits of a mnemonic phrase do not match its entropy
+    InvalidMnemonicChecksum,
+    /// A key file is malformed, uses unsupported parameters or holds a key of another curve
+    InvalidKeystore,
+    /// Authenticated decryption failed: the password is wrong or the ciphertext was modified
+    DecryptionFailed,
+    /// A public key stored alongside a secret key is not the public key of that secret
+    KeyMismatch,
+    /// The requested key derivation output is longer than the KDF can produce
+    InvalidKeyLength,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::InvalidHex => write!(f, "input is not valid hex"),
+            Error::MalformedPoint => write!(f, "malformed point"),
+            Error::InvalidEncoding => write!(f, "invalid SEC1 encoding"),
+            Error::PointNotOnCurve => write!(f, "point is not on the curve"),
+            Error::InvalidPublicKey => write!(
+                f,
+                "public key or a point derived from it is the point at infinity"
+            ),
+            Error::SingularCurve => write!(f, "curve is singular"),
+            Error::GeneratorIsIdentity => write!(f, "generator is the point at infinity"),
+            Error::GeneratorNotOnCurve => write!(f, "generator is not on the curve"),
+            Error::InvalidGeneratorOrder => write!(f, "generator does not have the declared order"),
+            Error::OrderOutsideHasseBound => write!(f, "curve order is outside the Hasse bound"),
+            Error::SecretOutOfRange => write!(f, "secret key is not in [1, order)"),
+            Error::ScalarOutOfRange => write!(f, "scalar is not in [0, order)"),
+            Error::InvalidSignature => write!(f, "invalid signature"),
+            Error::NotInvertible => write!(f, "element is not invertible"),
+            Error::NonceReuse => write!(f, "nonce was already used for a different message"),
+            Error::InvalidMnemonic => write!(f, "invalid mnemonic phrase"),
+            Error::InvalidMnemonicChecksum => write!(f, "mnemonic checksum mismatch"),
+            Error::InvalidKeystore => write!(f, "invalid key file"),
+            Error::DecryptionFailed => write!(f, "wrong password or corrupted key file"),
+            Error::KeyMismatch => write!(f, "public key does not match the secret key"),
+            Error::InvalidKeyLength => write!(f, "requested key length is too long"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use num_bigint::BigUint;
+
+    use super::Error;
+    use crate::{
+        digital_signature::Signature,
+        ecdsa::{EcdsaSignature, SigningContext},
+        elliptic_curves::{
+            arithmetic::try_mod_inv,
+            curve::{Curve, CurvePoint},
+            generic::GenericCurve,
+            scalar::Scalar,
+            sec1::from_sec1,
+            secp256k1::Secp256k1,
+        },
+        mnemonic::Mnemonic,
+        public_key::PublicKey,
+    };
+
+    /// y^2 = x^3 + b (mod 17) with the point at infinity as generator, which is singular for b = 0
+    struct DegenerateCurve {
+        b: u8,
+    }
+
+    impl Curve for DegenerateCurve {
+        fn generator_point(&self) -> CurvePoint {
+            CurvePoint::Infinity
+        }
+
+        fn prime_modulus(&self) -> BigUint {
+            BigUint::from(17_u8)
+        }
+
+        fn a(&self) -> BigUint {
+            BigUint::ZERO
+        }
+
+        fn b(&self) -> BigUint {
+            BigUint::from(self.b)
+        }
+
+        fn order(&self) -> BigUint {
+            BigUint::from(17_u8)
+        }
+
+        fn identity(&self) -> CurvePoint {
+            CurvePoint::Infinity
+        }
+    }
+
+    #[test]
+    fn should_surface_every_variant_from_the_public_api() {
+        let p = Secp256k1.prime_modulus();
+        let signer = Signature::from_secret_hex(Secp256k1, "1").unwrap();
+        let mut context = SigningContext::new(&signer);
+        let nonce = BigUint::from(2_u8);
+        context.sign_with_nonce(&p, nonce.clone()).unwrap();
+        let mut mismatched_der = signer.to_sec1_der();
+        mismatched_der[7] ^= 1;
+
+        let errors = [
+            Signature::from_secret_hex(Secp256k1, "xyz").err(),
+            "(1, z)".parse::<CurvePoint>().err(),
+            from_sec1(&[0x05], &Secp256k1).err(),
+            from_sec1(&[[0x04].as_slice(), &[0; 64]].concat(), &Secp256k1).err(),
+            PublicKey::new(Secp256k1, CurvePoint::Infinity).err(),
+            DegenerateCurve { b: 0 }.validate().err(),
+            DegenerateCurve { b: 3 }.validate().err(),
+            GenericCurve {
+                n: BigUint::from(38_u8),
+                ..GenericCurve::toy()
+            }
+            .validated()
+            .err(),
+            Signature::from_secret_hex(Secp256k1, "0").err(),
+            Scalar::<Secp256k1>::try_from([0xff; 32].as_slice()).err(),
+            EcdsaSignature::from_compact(&[0; 64], &Secp256k1).err(),
+            try_mod_inv(&p, &p).err(),
+            context.sign_with_nonce(&BigUint::ZERO, nonce).err(),
+            "abandon".parse::<Mnemonic>().err(),
+            ["abandon"; 12].join(" ").parse::<Mnemonic>().err(),
+            Signature::from_sec1_der(Secp256k1, &mismatched_der).err(),
+            signer
+                .ecdh_kdf(&signer.to_public_key().unwrap(), b"", 8161)
+                .err(),
+        ];
+
+        assert_eq!(
+            errors,
+            [
+                Some(Error::InvalidHex),
+                Some(Error::MalformedPoint),
+                Some(Error::InvalidEncoding),
+                Some(Error::PointNotOnCurve),
+                Some(Error::InvalidPublicKey),
+                Some(Error::SingularCurve),
+                Some(Error::GeneratorIsIdentity),
+                Some(Error::OrderOutsideHasseBound),
+                Some(Error::SecretOutOfRange),
+                Some(Error::ScalarOutOfRange),
+                Some(Error::InvalidSignature),
+                Some(Error::NotInvertible),
+                Some(Error::NonceReuse),
+                Some(Error::InvalidMnemonic),
+                Some(Error::InvalidMnemonicChecksum),
+                Some(Error::KeyMismatch),
+                Some(Error::InvalidKeyLength),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_display_distinct_messages() {
+        let variants = [
+            Error::InvalidHex,
+            Error::MalformedPoint,
+            Error::InvalidEncoding,
+            Error::PointNotOnCurve,
+            Error::InvalidPublicKey,
+            Error::SingularCurve,
+            Error::GeneratorIsIdentity,
+            Error::GeneratorNotOnCurve,
+            Error::InvalidGeneratorOrder,
+            Error::OrderOutsideHasseBound,
+            Error::SecretOutOfRange,
+            Error::ScalarOutOfRange,
+            Error::InvalidSignature,
+            Error::NotInvertible,
+            Error::NonceReuse,
+            Error::InvalidMnemonic,
+            Error::InvalidMnemonicChecksum,
+            Error::InvalidKeystore,
+            Error::DecryptionFailed,
+            Error::KeyMismatch,
+            Error::InvalidKeyLength,
+        ];
+
+        for (i, first) in variants.iter().enumerate() {
+            assert!(!first.to_string().is_empty());
+            for second in &variants[i + 1..] {
+                assert_ne!(first.to_string(), second.to_string());
+            }
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! Password-encrypted key files, enabled by the `keystore` feature.
+//!
+//! The layout follows the Ethereum keystore v3 format, with AES-256-GCM in place of AES-128-CTR and a separate
+//! MAC, so the files are not interchangeable with Ethereum wallets:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "curve": "secp256k1",
+//!   "crypto": {
+//!     "cipher": "aes-256-gcm",
+//!     "cipherparams": { "nonce": "<12 bytes, hex>" },
+//!     "ciphertext": "<secret key followed by the 16-byte tag, hex>",
+//!     "kdf": "scrypt",
+//!     "kdfparams": { "dklen": 32, "n": 131072, "r": 8, "p": 1, "salt": "<32 bytes, hex>" }
+//!   }
+//! }
+//! ```
+//!
+//! The curve name (see [`Curve::name`]) is authenticated as associated data, so a file can't be passed off as
+//! a key of another curve.
+
+use alloc::string::String;
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm, Nonce,
+};
+use rand::{thread_rng, RngCore};
+use serde_json::{json, Value};
+
+use crate::{
+    digital_signature::Signature,
+    elliptic_curves::{
+        curve::Curve,
+        scalar::{scalar_from_be_bytes, scalar_to_be_bytes},
+    },
+    error::Error,
+};
+
+const VERSION: u64 = 1;
+const CIPHER: &str = "aes-256-gcm";
+const KDF: &str = "scrypt";
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const SALT_LEN: usize = 32;
+
+/// Caps on the scrypt cost a key file may ask for: 8 times the default \( N \), and 8 times the default
+/// memory of 128 MiB. Without them a crafted file could make decryption allocate more memory than exists.
+const MAX_LOG_N: u8 = 20;
+const MAX_MEMORY: u64 = 1 << 30;
+
+/// The scrypt cost parameters: \( N = 2^{log\_n} \) iterations, block size `r` and parallelism `p`.
+///
+/// Deriving a key takes \( 128 \cdot r \cdot N \) bytes of memory, which is what makes guessing passwords
+/// expensive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KdfParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+/// \( N = 2^{17} \), `r` = 8 and `p` = 1, i.e. 128 MiB per derivation
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            log_n: 17,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+impl<T: Curve> Signature<T> {
+    /// Encrypts the secret key under `password` with the default [`KdfParams`], see the [module](self) docs
+    /// for the format.
+    pub fn to_encrypted_json(&self, password: &str) -> String {
+        self.to_encrypted_json_with_rng(password, KdfParams::default(), &mut thread_rng())
+            .expect("The default scrypt parameters are valid")
+    }
+
+    /// Like [`Signature::to_encrypted_json`], with explicit scrypt parameters and a caller-supplied source of
+    /// randomness for the salt and the nonce.
+    ///
+    /// # Errors
+    /// - `Error::InvalidKeystore` if scrypt rejects `params`.
+    pub fn to_encrypted_json_with_rng<R: RngCore + ?Sized>(
+        &self,
+        password: &str,
+        params: KdfParams,
+        rng: &mut R,
+    ) -> Result<String, Error> {
+        let mut salt = [0; SALT_LEN];
+        rng.fill_bytes(&mut salt);
+        let mut nonce = [0; NONCE_LEN];
+        rng.fill_bytes(&mut nonce);
+
+        let key = derive_key(password, &salt, params)?;
+        let ciphertext = Aes256Gcm::new(&key.into())
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &scalar_to_be_bytes(&self.secret, &self.curve),
+                    aad: self.curve.name().as_bytes(),
+                },
+            )
+            .expect("AES-GCM encrypts messages of any practical length");
+
+        let keystore = json!({
+            "version": VERSION,
+            "curve": self.curve.name(),
+            "crypto": {
+                "cipher": CIPHER,
+                "cipherparams": { "nonce": hex::encode(nonce) },
+                "ciphertext": hex::encode(ciphertext),
+                "kdf": KDF,
+                "kdfparams": {
+                    "dklen": KEY_LEN,
+                    "n": 1_u64 << params.log_n,
+                    "r": params.r,
+                    "p": params.p,
+                    "salt": hex::encode(salt),
+                },
+            },
+        });
+        Ok(keystore.to_string())
+    }
+
+    /// Decrypts a key file written by [`Signature::to_encrypted_json`].
+    ///
+    /// # Errors
+    /// - `Error::InvalidKeystore` if `json` is not a key file in the format above, uses parameters scrypt
+    ///   rejects or that need more than 8 times the default memory, or holds a key of another curve.
+    /// - `Error::DecryptionFailed` if the password is wrong or the file was tampered with.
+    /// - `Error::SecretOutOfRange` if the decrypted secret is not in `[1, order)`.
+    pub fn from_encrypted_json(curve: T, json: &str, password: &str) -> Result<Self, Error> {
+        let keystore: Value = serde_json::from_str(json).map_err(|_| Error::InvalidKeystore)?;
+        let crypto = &keystore["crypto"];
+        if keystore["version"] != VERSION
+            || keystore["curve"] != curve.name()
+            || crypto["cipher"] != CIPHER
+            || crypto["kdf"] != KDF
+        {
+            return Err(Error::InvalidKeystore);
+        }
+
+        let bytes = |value: &Value| {
+            let hex = value.as_str().ok_or(Error::InvalidKeystore)?;
+            hex::decode(hex).map_err(|_| Error::InvalidKeystore)
+        };
+        let integer = |value: &Value| {
+            let integer = value.as_u64().ok_or(Error::InvalidKeystore)?;
+            u32::try_from(integer).map_err(|_| Error::InvalidKeystore)
+        };
+
+        let kdf_params = &crypto["kdfparams"];
+        let n = kdf_params["n"].as_u64().ok_or(Error::InvalidKeystore)?;
+        if kdf_params["dklen"] != KEY_LEN || !n.is_power_of_two() {
+            return Err(Error::InvalidKeystore);
+        }
+        let params = KdfParams {
+            log_n: n.trailing_zeros() as u8,
+            r: integer(&kdf_params["r"])?,
+            p: integer(&kdf_params["p"])?,
+        };
+        if params.log_n > MAX_LOG_N || (128 * u64::from(params.r)) << params.log_n > MAX_MEMORY {
+            return Err(Error::InvalidKeystore);
+        }
+
+        let nonce = bytes(&crypto["cipherparams"]["nonce"])?;
+        if nonce.len() != NONCE_LEN {
+            return Err(Error::InvalidKeystore);
+        }
+
+        let key = derive_key(password, &bytes(&kdf_params["salt"])?, params)?;
+        let secret = Aes256Gcm::new(&key.into())
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &bytes(&crypto["ciphertext"])?,
+                    aad: curve.name().as_bytes(),
+                },
+            )
+            .map_err(|_| Error::DecryptionFailed)?;
+
+        let secret = scalar_from_be_bytes(&secret, &curve).map_err(|_| Error::InvalidKeystore)?;
+        if !curve.is_valid_scalar(&secret) {
+            return Err(Error::SecretOutOfRange);
+        }
+
+        let public_key = curve.calculate_public_key(secret.clone());
+        Ok(Self {
+            curve,
+            secret,
+            public_key,
+        })
+    }
+}
+
+fn derive_key(password: &str, salt: &[u8], params: KdfParams) -> Result<[u8; KEY_LEN], Error> {
+    let params = scrypt::Params::new(params.log_n, params.r, params.p, KEY_LEN)
+        .map_err(|_| Error::InvalidKeystore)?;
+
+    let mut key = [0; KEY_LEN];
+    scrypt::scrypt(password.as_bytes(), salt, &params, &mut key)
+        .map_err(|_| Error::InvalidKeystore)?;
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+    use serde_json::Value;
+
+    use super::KdfParams;
+    use crate::{
+        digital_signature::Signature,
+        elliptic_curves::{curve::Curve, p256::P256, secp256k1::Secp256k1},
+        error::Error,
+    };
+
+    /// Far too cheap for real keys, but keeps the tests fast
+    const TEST_PARAMS: KdfParams = KdfParams {
+        log_n: 4,
+        r: 8,
+        p: 1,
+    };
+
+    fn encrypt<T: Curve>(keypair: &Signature<T>, password: &str) -> String {
+        let mut rng = StdRng::seed_from_u64(7);
+        keypair
+            .to_encrypted_json_with_rng(password, TEST_PARAMS, &mut rng)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_round_trip_with_password() {
+        fn check<T: Curve + Clone>(curve: T) {
+            let keypair = Signature::from_seed(curve.clone(), b"keystore");
+            let json = encrypt(&keypair, "correct horse battery staple");
+
+            let keystore: Value = serde_json::from_str(&json).unwrap();
+            assert_eq!(keystore["curve"], curve.name());
+            assert_eq!(keystore["crypto"]["kdfparams"]["n"], 16);
+
+            let decrypted =
+                Signature::from_encrypted_json(curve, &json, "correct horse battery staple")
+                    .unwrap();
+            assert_eq!(decrypted.secret, keypair.secret);
+            assert_eq!(decrypted.public_key, keypair.public_key);
+        }
+
+        check(Secp256k1);
+        check(P256);
+    }
+
+    #[test]
+    fn should_reject_wrong_password() {
+        let keypair = Signature::from_seed(Secp256k1, b"keystore");
+        let json = encrypt(&keypair, "correct horse battery staple");
+
+        assert_eq!(
+            Signature::from_encrypted_json(Secp256k1, &json, "Tr0ub4dor&3").err(),
+            Some(Error::DecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn should_reject_tampered_or_foreign_files() {
+        let keypair = Signature::from_seed(Secp256k1, b"keystore");
+        let json = encrypt(&keypair, "password");
+
+        // A single flipped ciphertext bit fails authentication
+        let mut keystore: Value = serde_json::from_str(&json).unwrap();
+        let ciphertext = keystore["crypto"]["ciphertext"].as_str().unwrap();
+        let flipped = if ciphertext.starts_with('0') {
+            "1"
+        } else {
+            "0"
+        };
+        keystore["crypto"]["ciphertext"] = (flipped.to_owned() + &ciphertext[1..]).into();
+        assert_eq!(
+            Signature::from_encrypted_json(Secp256k1, &keystore.to_string(), "password").err(),
+            Some(Error::DecryptionFailed)
+        );
+
+        // Relabeling the curve fails too, since the name is authenticated
+        let mut keystore: Value = serde_json::from_str(&json).unwrap();
+        keystore["curve"] = "P-256".into();
+        assert_eq!(
+            Signature::from_encrypted_json(P256, &keystore.to_string(), "password").err(),
+            Some(Error::DecryptionFailed)
+        );
+
+        // A key of another curve, or no key file at all
+        assert_eq!(
+            Signature::from_encrypted_json(P256, &json, "password").err(),
+            Some(Error::InvalidKeystore)
+        );
+
+        // Costs that would exhaust memory are refused before running scrypt
+        for (n, r) in [(1_u64 << 40, 8), (1 << 17, 1 << 20)] {
+            let mut keystore: Value = serde_json::from_str(&json).unwrap();
+            keystore["crypto"]["kdfparams"]["n"] = n.into();
+            keystore["crypto"]["kdfparams"]["r"] = r.into();
+            assert_eq!(
+                Signature::from_encrypted_json(Secp256k1, &keystore.to_string(), "password").err(),
+                Some(Error::InvalidKeystore)
+            );
+        }
+
+        for malformed in ["{}", "not json"] {
+            assert_eq!(
+                Signature::from_encrypted_json(Secp256k1, malformed, "password").err(),
+                Some(Error::InvalidKeystore)
+            );
+        }
+    }
+}
@@ -1,2 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod address;
+mod der;
 pub mod digital_signature;
+pub mod ecdh;
+pub mod ecdsa;
 pub mod elliptic_curves;
+pub mod error;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+pub mod mnemonic;
+pub mod musig;
+pub mod public_key;
+pub mod schnorr;
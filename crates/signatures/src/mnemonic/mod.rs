@@ -0,0 +1,261 @@
+//! BIP39 mnemonic phrases: from entropy to words and back, and from words to a seed and a keypair.
+//!
+//! Only the English wordlist is included. The seed is PBKDF2-HMAC-SHA512 of the phrase, salted with
+//! `"mnemonic"` followed by the passphrase, as BIP39 specifies. BIP39 also asks for both strings to be
+//! NFKD-normalized; the English words are ASCII and need no normalization, but a non-ASCII passphrase must be
+//! normalized by the caller.
+
+mod english;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use sha2::{Digest, Sha256, Sha512};
+
+use crate::{digital_signature::Signature, elliptic_curves::curve::Curve, error::Error};
+use english::WORDS;
+
+/// Number of PBKDF2 rounds fixed by BIP39
+const SEED_ROUNDS: u32 = 2048;
+
+/// Each word encodes 11 bits, an index into the 2048-word list
+const BITS_PER_WORD: usize = 11;
+
+/// A mnemonic phrase, stored as the entropy it encodes.
+///
+/// The phrase has 12, 15, 18, 21 or 24 words for 128, 160, 192, 224 or 256 bits of entropy. Its last word also
+/// carries a checksum: the first `entropy bits / 32` bits of SHA-256 of the entropy.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mnemonic {
+    entropy: Vec<u8>,
+}
+
+impl Mnemonic {
+    /// Encodes `entropy` as a phrase.
+    ///
+    /// # Errors
+    /// - `Error::InvalidMnemonic` if `entropy` is not 16, 20, 24, 28 or 32 bytes long.
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self, Error> {
+        if !matches!(entropy.len(), 16 | 20 | 24 | 28 | 32) {
+            return Err(Error::InvalidMnemonic);
+        }
+        Ok(Self {
+            entropy: entropy.to_vec(),
+        })
+    }
+
+    /// Returns the entropy encoded by the phrase
+    pub fn entropy(&self) -> &[u8] {
+        &self.entropy
+    }
+
+    /// Returns the number of words in the phrase
+    pub fn word_count(&self) -> usize {
+        self.entropy.len() * 8 * 33 / 32 / BITS_PER_WORD
+    }
+
+    /// Returns the words of the phrase in order
+    pub fn words(&self) -> impl Iterator<Item = &'static str> + '_ {
+        let mut bits = self.entropy.clone();
+        bits.push(checksum(&self.entropy));
+
+        (0..self.word_count()).map(move |word| {
+            let index = (0..BITS_PER_WORD).fold(0, |index, bit| {
+                let position = word * BITS_PER_WORD + bit;
+                (index << 1) | usize::from((bits[position / 8] >> (7 - position % 8)) & 1)
+            });
+            WORDS[index]
+        })
+    }
+
+    /// Derives the 64-byte BIP39 seed, which BIP32 and most wallets take as their root secret.
+    ///
+    /// An empty `passphrase` is the usual default. Every passphrase yields a valid but different seed.
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        let mut salt = String::from("mnemonic");
+        salt.push_str(passphrase);
+
+        let mut seed = [0; 64];
+        pbkdf2::pbkdf2_hmac::<Sha512>(
+            self.to_string().as_bytes(),
+            salt.as_bytes(),
+            SEED_ROUNDS,
+            &mut seed,
+        );
+        seed
+    }
+
+    /// Derives a keypair on `curve` from the BIP39 seed with `Signature::from_seed`.
+    ///
+    /// This reduces the seed to a scalar directly; it is not a BIP32 master key, so wallets following BIP32/BIP44
+    /// will derive different keys from the same phrase.
+    pub fn to_keypair<T: Curve>(&self, curve: T, passphrase: &str) -> Signature<T> {
+        Signature::from_seed(curve, &self.to_seed(passphrase))
+    }
+}
+
+/// Returns the first byte of SHA-256 of `entropy`, whose leading `entropy bits / 32` bits are the checksum
+fn checksum(entropy: &[u8]) -> u8 {
+    Sha256::digest(entropy)[0]
+}
+
+/// Formats the phrase as its words separated by single spaces
+impl core::fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, word) in self.words().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(word)?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints the length of the phrase, but never its words, which are as secret as the keys derived from them
+impl core::fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Mnemonic")
+            .field("word_count", &self.word_count())
+            .field("words", &format_args!("[REDACTED]"))
+            .finish()
+    }
+}
+
+/// Parses a phrase of lowercase English words separated by whitespace and checks its checksum
+impl core::str::FromStr for Mnemonic {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let indices = s
+            .split_whitespace()
+            .map(|word| {
+                WORDS
+                    .binary_search(&word)
+                    .map_err(|_| Error::InvalidMnemonic)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if !matches!(indices.len(), 12 | 15 | 18 | 21 | 24) {
+            return Err(Error::InvalidMnemonic);
+        }
+
+        // The words hold the entropy followed by the checksum, which always fits in one extra byte
+        let entropy_bytes = indices.len() * BITS_PER_WORD * 32 / 33 / 8;
+        let mut bits = vec![0_u8; entropy_bytes + 1];
+        for (word, index) in indices.iter().enumerate() {
+            for bit in 0..BITS_PER_WORD {
+                if (index >> (BITS_PER_WORD - 1 - bit)) & 1 == 1 {
+                    let position = word * BITS_PER_WORD + bit;
+                    bits[position / 8] |= 0x80 >> (position % 8);
+                }
+            }
+        }
+
+        let checksum_bits = entropy_bytes / 4;
+        let mask = 0xff_u8 << (8 - checksum_bits);
+        let (entropy, stored) = bits.split_at(entropy_bytes);
+        if stored[0] != checksum(entropy) & mask {
+            return Err(Error::InvalidMnemonicChecksum);
+        }
+
+        Self::from_entropy(entropy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, vec::Vec};
+
+    use super::Mnemonic;
+    use crate::{
+        digital_signature::Signature, elliptic_curves::secp256k1::Secp256k1, error::Error,
+    };
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Entropy, phrase and seed (with the passphrase "TREZOR") from the reference BIP39 test vectors
+    const VECTORS: [(&str, &str, &str); 4] = [
+        (
+            "00000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        ),
+        (
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+        ),
+        (
+            "808080808080808080808080808080808080808080808080",
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter always",
+            "107d7c02a5aa6f38c58083ff74f04c607c2d2c0ecc55501dadd72d025b751bc27fe913ffb796f841c49b1d33b610cf0e91d3aa239027f5e99fe4ce9e5088cd65",
+        ),
+        (
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+            "dd48c104698c30cfe2b6142103248622fb7bb0ff692eebb00089b32d22484e1613912f0a5b694407be899ffd31ed3992c456cdf60f5d4564b8ba3f05a69890ad",
+        ),
+    ];
+
+    #[test]
+    fn should_match_reference_vectors() {
+        for (entropy, phrase, seed) in VECTORS {
+            let mnemonic = Mnemonic::from_entropy(&from_hex(entropy)).unwrap();
+            assert_eq!(mnemonic.to_string(), phrase);
+            assert_eq!(phrase.parse::<Mnemonic>(), Ok(mnemonic.clone()));
+            assert_eq!(mnemonic.to_seed("TREZOR").as_slice(), from_hex(seed));
+        }
+    }
+
+    #[test]
+    fn should_derive_keypair_from_seed() {
+        let mnemonic: Mnemonic = VECTORS[0].1.parse().unwrap();
+        let keypair = mnemonic.to_keypair(Secp256k1, "TREZOR");
+        let expected = Signature::from_seed(Secp256k1, &from_hex(VECTORS[0].2));
+
+        assert_eq!(keypair.secret, expected.secret);
+        assert_ne!(mnemonic.to_keypair(Secp256k1, "").secret, expected.secret);
+    }
+
+    #[test]
+    fn should_reject_bad_checksum() {
+        // "abandon" x 12 encodes zero entropy, whose checksum selects "about" as the last word
+        let phrase = ["abandon"; 12].join(" ");
+        assert_eq!(
+            phrase.parse::<Mnemonic>(),
+            Err(Error::InvalidMnemonicChecksum)
+        );
+    }
+
+    #[test]
+    fn should_reject_malformed_phrases() {
+        let unknown_word = VECTORS[0].1.replace("about", "abut");
+        let too_short = VECTORS[0].1.split_once(' ').unwrap().1;
+        let uppercase = VECTORS[1].1.to_uppercase();
+
+        for phrase in [unknown_word.as_str(), too_short, &uppercase, ""] {
+            assert_eq!(phrase.parse::<Mnemonic>(), Err(Error::InvalidMnemonic));
+        }
+        assert_eq!(
+            Mnemonic::from_entropy(&[0; 15]),
+            Err(Error::InvalidMnemonic)
+        );
+    }
+
+    #[test]
+    fn should_not_print_words_in_debug_output() {
+        let mnemonic: Mnemonic = VECTORS[1].1.parse().unwrap();
+        let debug = format!("{:?}", mnemonic);
+
+        assert!(debug.contains("word_count: 12"));
+        assert!(!debug.contains("legal"));
+    }
+}
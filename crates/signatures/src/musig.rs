@@ -0,0 +1,240 @@
+//! A prototype of MuSig-style multisignatures on top of BIP340. Every signer contributes a partial signature,
+//! and the combined signature is an ordinary Schnorr signature for the aggregate key, checked with
+//! `schnorr_verify`.
+//!
+//! **Not for production.** There is no nonce commitment round (MuSig1) or second nonce (MuSig2), so a malicious
+//! co-signer running concurrent sessions can forge signatures. The hashes follow BIP327's tags but keys are
+//! x-only, so the output is not interoperable with BIP327.
+
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+use rand::RngCore;
+
+use crate::{
+    digital_signature::Signature,
+    elliptic_curves::{
+        arithmetic::{add_two_points, multi_scalar_mul},
+        curve::{Curve, CurvePoint},
+        secp256k1::Secp256k1,
+    },
+    schnorr::{challenge, has_even_y, tagged_hash, to_bytes32, x_bytes},
+};
+
+/// Computes the key aggregation coefficient \( a_i = H_{agg}(L \| X_i) \mod n \) of `public_key`, where
+/// \( L = H_{list}(X_1 \| \dots \| X_m) \) commits to every key of the group.
+///
+/// Weighting each key by its coefficient prevents rogue-key attacks, where a signer picks their key as a
+/// function of the others' to control the aggregate.
+pub fn key_agg_coefficient(public_keys: &[[u8; 32]], public_key: &[u8; 32]) -> BigUint {
+    let keys: Vec<&[u8]> = public_keys.iter().map(|key| key.as_slice()).collect();
+    let list = tagged_hash("KeyAgg list", &keys);
+    let hash = tagged_hash("KeyAgg coefficient", &[&list, public_key]);
+    Secp256k1.reduce_scalar(&BigUint::from_bytes_be(&hash))
+}
+
+/// Aggregates x-only public keys into the x-only key \( Q = \sum a_i P_i \) the combined signature verifies
+/// against.
+///
+/// # Returns
+/// - `None` if a key is not the x-coordinate of a curve point or the keys cancel out.
+pub fn aggregate_public_keys(public_keys: &[[u8; 32]]) -> Option<[u8; 32]> {
+    aggregate_point(public_keys).map(|point| x_bytes(&point))
+}
+
+/// Draws a secret nonce \( k_i \in [1, n) \) and its public nonce \( R_i = k_i G \).
+///
+/// The public nonce is shared with the other signers, the secret one must be used for a single signature.
+pub fn musig_nonce_with_rng<R: RngCore + ?Sized>(rng: &mut R) -> (BigUint, CurvePoint) {
+    let secret_nonce = Secp256k1.generate_secret_key_with_rng(rng);
+    let public_nonce = Secp256k1.calculate_public_key(secret_nonce.clone());
+    (secret_nonce, public_nonce)
+}
+
+/// Combines the signers' partial signatures into a 64-byte BIP340 signature `bytes(R.x) || bytes(s)`, where
+/// \( R = \sum R_i \) and \( s = \sum s_i \mod n \).
+///
+/// # Returns
+/// - `None` if the public nonces add up to the point at infinity.
+pub fn musig_combine(
+    public_nonces: &[CurvePoint],
+    partial_signatures: &[BigUint],
+) -> Option<[u8; 64]> {
+    let nonce_point = aggregate_nonce(public_nonces)?;
+    let s = Secp256k1.reduce_scalar(&partial_signatures.iter().sum());
+
+    let mut signature = [0_u8; 64];
+    signature[..32].copy_from_slice(&x_bytes(&nonce_point));
+    signature[32..].copy_from_slice(&to_bytes32(&s));
+    Some(signature)
+}
+
+impl Signature<Secp256k1> {
+    /// Produces this signer's share of a multisignature over `message` by the group `public_keys`.
+    ///
+    /// With \( g = \pm 1 \) chosen so that the aggregate key \( Q \) and the aggregate nonce \( R \) have even
+    /// y-coordinates (as BIP340 expects), and \( d_i \) the secret normalized like in
+    /// [`Signature::schnorr_sign_with_aux`]:
+    /// 1. \( e = H_{challenge}(R_x \| Q_x \| m) \mod n \).
+    /// 2. \( s_i = g_R k_i + e a_i g_Q d_i \mod n \).
+    ///
+    /// `public_nonces` are the public nonces of every signer, including this one's for `secret_nonce`.
+    ///
+    /// # Returns
+    /// - `None` if the secret or the nonce is not in `[1, order)`, this signer's key is not part of the
+    ///   group, or the keys or nonces add up to the point at infinity.
+    pub fn musig_partial_sign(
+        &self,
+        message: &[u8],
+        public_keys: &[[u8; 32]],
+        secret_nonce: &BigUint,
+        public_nonces: &[CurvePoint],
+    ) -> Option<BigUint> {
+        let curve = &self.curve;
+        if !curve.is_valid_scalar(secret_nonce) {
+            return None;
+        }
+        // Normalize the secret for an even-y own key, then again for an even-y aggregate key
+        let (mut d, own_key) = self.even_y_secret()?;
+        if !public_keys.contains(&own_key) {
+            return None;
+        }
+
+        let key_point = aggregate_point(public_keys)?;
+        let nonce_point = aggregate_nonce(public_nonces)?;
+
+        if !has_even_y(&key_point) {
+            d = curve.negate_scalar(&d);
+        }
+        let k = if has_even_y(&nonce_point) {
+            secret_nonce.clone()
+        } else {
+            curve.negate_scalar(secret_nonce)
+        };
+
+        let e = challenge(&x_bytes(&nonce_point), &x_bytes(&key_point), message);
+        let a = key_agg_coefficient(public_keys, &own_key);
+        Some(curve.reduce_scalar(&(k + e * a * d)))
+    }
+}
+
+fn aggregate_point(public_keys: &[[u8; 32]]) -> Option<CurvePoint> {
+    let curve = Secp256k1;
+    let pairs = public_keys
+        .iter()
+        .map(|key| {
+            let point = curve.lift_x(&BigUint::from_bytes_be(key), true)?;
+            Some((key_agg_coefficient(public_keys, key), point))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    match multi_scalar_mul(&pairs, &curve) {
+        CurvePoint::Infinity => None,
+        point => Some(point),
+    }
+}
+
+fn aggregate_nonce(public_nonces: &[CurvePoint]) -> Option<CurvePoint> {
+    let sum = public_nonces
+        .iter()
+        .fold(CurvePoint::Infinity, |sum, nonce| {
+            add_two_points(sum, nonce.clone(), &Secp256k1)
+        });
+    (!sum.is_infinity()).then_some(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{aggregate_public_keys, musig_combine, musig_nonce_with_rng};
+    use crate::{
+        digital_signature::Signature,
+        elliptic_curves::{curve::CurvePoint, secp256k1::Secp256k1},
+        schnorr::schnorr_verify,
+    };
+
+    fn party(rng: &mut StdRng) -> Signature<Secp256k1> {
+        let mut signer = Signature {
+            curve: Secp256k1,
+            secret: BigUint::ZERO,
+            public_key: CurvePoint::Infinity,
+        };
+        signer.generate_keypair_with_rng(rng);
+        signer
+    }
+
+    #[test]
+    fn should_verify_combined_signature_against_aggregate_key() {
+        let message = b"2-of-2";
+        // Different seeds cover both parities of the own keys, the aggregate key and the aggregate nonce
+        for seed in 0..4 {
+            let rng = &mut StdRng::seed_from_u64(seed);
+            let (alice, bob) = (party(rng), party(rng));
+            let public_keys = [
+                alice.schnorr_public_key().unwrap(),
+                bob.schnorr_public_key().unwrap(),
+            ];
+            let aggregate_key = aggregate_public_keys(&public_keys).unwrap();
+
+            let (alice_nonce, alice_public_nonce) = musig_nonce_with_rng(rng);
+            let (bob_nonce, bob_public_nonce) = musig_nonce_with_rng(rng);
+            let public_nonces = [alice_public_nonce, bob_public_nonce];
+
+            let partial_signatures = [
+                alice
+                    .musig_partial_sign(message, &public_keys, &alice_nonce, &public_nonces)
+                    .unwrap(),
+                bob.musig_partial_sign(message, &public_keys, &bob_nonce, &public_nonces)
+                    .unwrap(),
+            ];
+            let signature = musig_combine(&public_nonces, &partial_signatures).unwrap();
+
+            assert!(schnorr_verify(message, &signature, &aggregate_key));
+            assert!(!schnorr_verify(b"3-of-3", &signature, &aggregate_key));
+            assert!(!schnorr_verify(
+                message,
+                &signature,
+                &alice.schnorr_public_key().unwrap()
+            ));
+
+            // A single party can't produce the signature on its own
+            let alone = musig_combine(&public_nonces, &partial_signatures[..1]).unwrap();
+            assert!(!schnorr_verify(message, &alone, &aggregate_key));
+        }
+    }
+
+    #[test]
+    fn should_refuse_to_sign_for_a_group_without_own_key() {
+        let rng = &mut StdRng::seed_from_u64(42);
+        let (alice, bob, carol) = (party(rng), party(rng), party(rng));
+        let public_keys = [
+            bob.schnorr_public_key().unwrap(),
+            carol.schnorr_public_key().unwrap(),
+        ];
+        let (nonce, public_nonce) = musig_nonce_with_rng(rng);
+
+        assert_eq!(
+            alice.musig_partial_sign(b"message", &public_keys, &nonce, &[public_nonce]),
+            None
+        );
+    }
+
+    #[test]
+    fn should_weight_keys_by_the_whole_group() {
+        let rng = &mut StdRng::seed_from_u64(7);
+        let (alice, bob) = (party(rng), party(rng));
+        let (alice_key, bob_key) = (
+            alice.schnorr_public_key().unwrap(),
+            bob.schnorr_public_key().unwrap(),
+        );
+
+        // A naive sum of the keys would be the same for both orders and for the key alone with itself
+        assert_ne!(
+            aggregate_public_keys(&[alice_key, bob_key]),
+            aggregate_public_keys(&[bob_key, alice_key])
+        );
+        assert_ne!(aggregate_public_keys(&[alice_key]), Some(alice_key));
+    }
+}
@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+
+use crate::{
+    digital_signature::Signature,
+    elliptic_curves::{
+        arithmetic::negate_point,
+        curve::{Curve, CurvePoint},
+        sec1::{from_sec1, to_sec1},
+    },
+    error::Error,
+};
+
+/// A public key, i.e. a point bundled with the curve it belongs to.
+///
+/// Carrying the curve along means operations on the key don't need it passed in separately. Unlike a bare
+/// `CurvePoint`, a `PublicKey` is always on its curve and never the point at infinity, so verification and ECDH
+/// don't have to check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey<T: Curve> {
+    curve: T,
+    point: CurvePoint,
+}
+
+impl<T: Curve> PublicKey<T> {
+    /// Wraps a point of `curve`.
+    ///
+    /// # Errors
+    /// - `Error::InvalidPublicKey` if `point` is the point at infinity.
+    /// - `Error::PointNotOnCurve` if `point` doesn't satisfy the curve equation or has a coordinate not below
+    ///   \( p \).
+    pub fn new(curve: T, point: CurvePoint) -> Result<Self, Error> {
+        if point.is_infinity() {
+            return Err(Error::InvalidPublicKey);
+        }
+        if !curve.is_on_curve(&point) {
+            return Err(Error::PointNotOnCurve);
+        }
+
+        Ok(Self { curve, point })
+    }
+
+    /// Decodes a (compressed or uncompressed) SEC1 public key of `curve`.
+    ///
+    /// # Errors
+    /// - Any error raised by `from_sec1`, or by `PublicKey::new` for the decoded point.
+    pub fn from_sec1(curve: T, bytes: &[u8]) -> Result<Self, Error> {
+        let point = from_sec1(bytes, &curve)?;
+        Self::new(curve, point)
+    }
+
+    /// Encodes the key in SEC1 form, see `sec1::to_sec1`
+    pub fn to_sec1(&self, compressed: bool) -> Vec<u8> {
+        to_sec1(&self.point, compressed, &self.curve)
+    }
+
+    /// Returns the curve the key belongs to
+    pub fn curve(&self) -> &T {
+        &self.curve
+    }
+
+    /// Returns the point, which is on the curve and not the point at infinity
+    pub fn point(&self) -> &CurvePoint {
+        &self.point
+    }
+
+    /// Consumes the key and returns its point
+    pub fn into_point(self) -> CurvePoint {
+        self.point
+    }
+
+    /// Returns the negated key \( -P = (x, p - y) \), which shares the x-coordinate of \( P \)
+    pub fn negate(self) -> Self {
+        let point = negate_point(self.point, &self.curve);
+        Self {
+            curve: self.curve,
+            point,
+        }
+    }
+}
+
+impl<T: Curve + Clone> Signature<T> {
+    /// Returns the public half of this keypair.
+    ///
+    /// # Errors
+    /// - Any error raised by [`PublicKey::new`], e.g. `Error::InvalidPublicKey` once the keypair was cleared or
+    ///   if `public_key` was set to the point at infinity.
+    pub fn to_public_key(&self) -> Result<PublicKey<T>, Error> {
+        PublicKey::new(self.curve.clone(), self.public_key.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::PublicKey;
+    use crate::{
+        digital_signature::Signature,
+        elliptic_curves::{
+            arithmetic::add_two_points,
+            curve::{Curve, CurvePoint},
+            p256::P256,
+            secp256k1::Secp256k1,
+        },
+        error::Error,
+    };
+
+    #[test]
+    fn should_return_original_key_when_negated_twice() {
+        let key = Signature::from_secret_hex(Secp256k1, "ABC")
+            .unwrap()
+            .to_public_key()
+            .unwrap();
+
+        let negated = key.clone().negate();
+        assert_ne!(negated, key);
+        assert!(Secp256k1.is_on_curve(negated.point()));
+        assert_eq!(negated.clone().negate(), key);
+    }
+
+    #[test]
+    fn should_sum_to_infinity_with_negation() {
+        let key = PublicKey::new(P256, P256.calculate_public_key(BigUint::from(7_u8))).unwrap();
+        let negated = key.clone().negate();
+
+        assert_eq!(
+            add_two_points(key.into_point(), negated.into_point(), &P256),
+            CurvePoint::Infinity
+        );
+    }
+
+    #[test]
+    fn should_reject_degenerate_points() {
+        assert_eq!(
+            PublicKey::new(Secp256k1, CurvePoint::Infinity),
+            Err(Error::InvalidPublicKey)
+        );
+
+        let CurvePoint::Affine { x, y } = Secp256k1.generator_point() else {
+            unreachable!()
+        };
+        let unreduced = CurvePoint::Affine {
+            x: x + Secp256k1.prime_modulus(),
+            y,
+        };
+        let off_curve = CurvePoint::Affine {
+            x: BigUint::from(1_u8),
+            y: BigUint::from(1_u8),
+        };
+        for point in [unreduced, off_curve] {
+            assert_eq!(
+                PublicKey::new(Secp256k1, point),
+                Err(Error::PointNotOnCurve)
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_cleared_keypair() {
+        let mut keypair = Signature::from_secret_hex(Secp256k1, "ABC").unwrap();
+        assert!(keypair.to_public_key().is_ok());
+
+        keypair.clear();
+        assert_eq!(keypair.to_public_key(), Err(Error::InvalidPublicKey));
+    }
+
+    #[test]
+    fn should_round_trip_sec1() {
+        let key = Signature::from_secret_hex(P256, "ABC")
+            .unwrap()
+            .to_public_key()
+            .unwrap();
+
+        for compressed in [true, false] {
+            let bytes = key.to_sec1(compressed);
+            assert_eq!(PublicKey::from_sec1(P256, &bytes), Ok(key.clone()));
+        }
+        assert_eq!(
+            PublicKey::from_sec1(P256, &[0x00]),
+            Err(Error::InvalidPublicKey)
+        );
+    }
+}
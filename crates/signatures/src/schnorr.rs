@@ -0,0 +1,355 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+#[cfg(feature = "std")]
+use rand::thread_rng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    digital_signature::Signature,
+    elliptic_curves::{
+        arithmetic::{multi_scalar_mul, negate_point},
+        constant_time::ct_eq,
+        curve::{Curve, CurvePoint},
+        secp256k1::Secp256k1,
+    },
+};
+
+impl Signature<Secp256k1> {
+    /// Returns the 32-byte x-only public key used by BIP340, derived from the secret.
+    ///
+    /// # Returns
+    /// - `None` if the secret is not in `[1, order)`.
+    pub fn schnorr_public_key(&self) -> Option<[u8; 32]> {
+        self.even_y_secret().map(|(_, px)| px)
+    }
+
+    /// Signs `message` following BIP340, drawing fresh auxiliary randomness for the nonce.
+    ///
+    /// # Returns
+    /// - The 64-byte signature `bytes(R.x) || bytes(s)`, or `None` if the secret is not in `[1, order)`.
+    #[cfg(feature = "std")]
+    pub fn schnorr_sign(&self, message: &[u8]) -> Option<[u8; 64]> {
+        self.schnorr_sign_with_rng(message, &mut thread_rng())
+    }
+
+    /// Signs `message` following BIP340, drawing the auxiliary randomness from the given generator.
+    pub fn schnorr_sign_with_rng<R: RngCore + ?Sized>(
+        &self,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Option<[u8; 64]> {
+        let mut aux = [0_u8; 32];
+        rng.fill_bytes(&mut aux);
+        self.schnorr_sign_with_aux(message, &aux)
+    }
+
+    /// Signs `message` following BIP340 with caller-provided auxiliary randomness.
+    ///
+    /// The nonce is derived deterministically from the secret, the public key, the message and `aux`:
+    /// 1. \( d = d' \) if \( P = d'G \) has an even y-coordinate, otherwise \( d = n - d' \).
+    /// 2. \( t = d \oplus H_{aux}(aux) \) and \( k' = H_{nonce}(t \| P_x \| m) \mod n \).
+    /// 3. \( R = k'G \), with \( k = k' \) if \( R \) has an even y-coordinate, otherwise \( k = n - k' \).
+    /// 4. \( e = H_{challenge}(R_x \| P_x \| m) \mod n \) and \( s = k + ed \mod n \).
+    ///
+    /// # Returns
+    /// - The 64-byte signature `bytes(R.x) || bytes(s)`, or `None` if the secret is not in `[1, order)`
+    ///   or the derived nonce is zero.
+    pub fn schnorr_sign_with_aux(&self, message: &[u8], aux: &[u8; 32]) -> Option<[u8; 64]> {
+        let curve = &self.curve;
+
+        // 1) Normalize the secret so that its public key has an even y-coordinate
+        let (d, px) = self.even_y_secret()?;
+
+        // 2) Derive the nonce from the masked secret
+        let mut t = to_bytes32(&d);
+        for (byte, mask) in t.iter_mut().zip(tagged_hash("BIP0340/aux", &[aux])) {
+            *byte ^= mask;
+        }
+        let rand = tagged_hash("BIP0340/nonce", &[&t, &px, message]);
+        let k0 = curve.reduce_scalar(&BigUint::from_bytes_be(&rand));
+        if ct_eq(&k0, &BigUint::ZERO) {
+            return None;
+        }
+
+        // 3) Normalize the nonce so that R has an even y-coordinate
+        let nonce_point = curve.calculate_public_key(k0.clone());
+        let k = if has_even_y(&nonce_point) {
+            k0
+        } else {
+            curve.negate_scalar(&k0)
+        };
+        let rx = x_bytes(&nonce_point);
+
+        // 4) s = k + e * d (mod n)
+        let e = challenge(&rx, &px, message);
+        let s = curve.reduce_scalar(&(k + e * d));
+
+        let mut signature = [0_u8; 64];
+        signature[..32].copy_from_slice(&rx);
+        signature[32..].copy_from_slice(&to_bytes32(&s));
+        Some(signature)
+    }
+
+    /// Computes \( P = d'G \) from the secret \( d' \), ignoring the stored `public_key`, and returns the
+    /// secret \( d = \pm d' \) whose public key has an even y-coordinate along with the x-coordinate of \( P \).
+    ///
+    /// # Returns
+    /// - `None` if the secret is not in `[1, order)`.
+    pub(crate) fn even_y_secret(&self) -> Option<(BigUint, [u8; 32])> {
+        let curve = &self.curve;
+        if ct_eq(&self.secret, &BigUint::ZERO) || self.secret >= curve.order() {
+            return None;
+        }
+
+        let public_point = curve.calculate_public_key(self.secret.clone());
+        let d = if has_even_y(&public_point) {
+            self.secret.clone()
+        } else {
+            curve.negate_scalar(&self.secret)
+        };
+        Some((d, x_bytes(&public_point)))
+    }
+}
+
+/// Verifies a BIP340 Schnorr signature against an x-only public key.
+///
+/// The signature is accepted iff \( R = sG - eP \) is not the point at infinity, has an even
+/// y-coordinate, and its x-coordinate equals the `r` half of the signature, i.e. \( sG = R + eP \).
+pub fn schnorr_verify(message: &[u8], signature: &[u8; 64], public_key: &[u8; 32]) -> bool {
+    let curve = Secp256k1;
+
+    let Some(public_point) = curve.lift_x(&BigUint::from_bytes_be(public_key), true) else {
+        return false;
+    };
+    let r = BigUint::from_bytes_be(&signature[..32]);
+    if r >= curve.prime_modulus() {
+        return false;
+    }
+    let s = BigUint::from_bytes_be(&signature[32..]);
+    if s >= curve.order() {
+        return false;
+    }
+
+    // R = sG - eP
+    let e = challenge(&signature[..32], public_key, message);
+    let pairs = [
+        (s, curve.generator_point()),
+        (curve.negate_scalar(&e), public_point),
+    ];
+
+    match multi_scalar_mul(&pairs, &curve) {
+        CurvePoint::Affine { x, y } => !y.bit(0) && x == r,
+        CurvePoint::Infinity => false,
+    }
+}
+
+/// Returns the x-only (BIP340/Taproot) encoding of a public key: the 32-byte big-endian x-coordinate.
+///
+/// The key is normalized with [`with_even_y`] first. A point and its negation share the same x-coordinate,
+/// so both map to the same bytes. The point at infinity has no x-coordinate and encodes as 32 zero bytes.
+pub fn x_only_public_key(point: &CurvePoint) -> Vec<u8> {
+    match with_even_y(point) {
+        CurvePoint::Affine { x, .. } => to_bytes32(&x).to_vec(),
+        CurvePoint::Infinity => Vec::from([0_u8; 32]),
+    }
+}
+
+/// Returns the point itself if its y-coordinate is even, otherwise its negation \( -P = (x, p - y) \).
+pub fn with_even_y(point: &CurvePoint) -> CurvePoint {
+    match point {
+        CurvePoint::Affine { y, .. } if y.bit(0) => negate_point(point.clone(), &Secp256k1),
+        _ => point.clone(),
+    }
+}
+
+/// Computes the BIP340 challenge \( e = H_{challenge}(R_x \| P_x \| m) \mod n \).
+pub(crate) fn challenge(rx: &[u8], px: &[u8], message: &[u8]) -> BigUint {
+    let hash = tagged_hash("BIP0340/challenge", &[rx, px, message]);
+    Secp256k1.reduce_scalar(&BigUint::from_bytes_be(&hash))
+}
+
+/// Computes the BIP340 tagged hash `SHA256(SHA256(tag) || SHA256(tag) || data)`.
+pub(crate) fn tagged_hash(tag: &str, data: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    for chunk in data {
+        hasher.update(chunk);
+    }
+    hasher.finalize().into()
+}
+
+pub(crate) fn has_even_y(point: &CurvePoint) -> bool {
+    matches!(point, CurvePoint::Affine { y, .. } if !y.bit(0))
+}
+
+pub(crate) fn x_bytes(point: &CurvePoint) -> [u8; 32] {
+    match point {
+        CurvePoint::Affine { x, .. } => to_bytes32(x),
+        CurvePoint::Infinity => unreachable!("Nonce and key points are never the identity"),
+    }
+}
+
+/// Serializes a value as a 32-byte big-endian array, left-padded with zeros.
+pub(crate) fn to_bytes32(value: &BigUint) -> [u8; 32] {
+    let bytes = value.to_bytes_be();
+    let mut out = [0_u8; 32];
+    out[32 - bytes.len()..].copy_from_slice(&bytes);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{schnorr_verify, with_even_y, x_only_public_key};
+    use crate::{
+        digital_signature::Signature,
+        elliptic_curves::{
+            arithmetic::negate_point,
+            curve::{Curve, CurvePoint},
+            secp256k1::Secp256k1,
+        },
+    };
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut out = [0_u8; N];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    fn signer(secret_hex: &str) -> Signature<Secp256k1> {
+        Signature::from_secret_hex(Secp256k1, secret_hex).unwrap()
+    }
+
+    /// (secret key, public key, aux_rand, message, signature) from the BIP340 test vectors
+    const VECTORS: [(&str, &str, &str, &str, &str); 2] = [
+        (
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+        ),
+        (
+            "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+        ),
+    ];
+
+    #[test]
+    fn should_match_bip340_test_vectors() {
+        for (secret, public_key, aux, message, expected) in VECTORS {
+            let signer = signer(secret);
+            let message: [u8; 32] = from_hex(message);
+
+            assert_eq!(signer.schnorr_public_key(), Some(from_hex(public_key)));
+
+            let signature = signer
+                .schnorr_sign_with_aux(&message, &from_hex(aux))
+                .unwrap();
+            assert_eq!(signature, from_hex::<64>(expected));
+            assert!(schnorr_verify(&message, &signature, &from_hex(public_key)));
+        }
+    }
+
+    #[test]
+    fn should_verify_freshly_created_signature() {
+        let signer = signer("B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF");
+        let signature = signer.schnorr_sign(b"hello").unwrap();
+
+        assert!(schnorr_verify(
+            b"hello",
+            &signature,
+            &signer.schnorr_public_key().unwrap()
+        ));
+        assert!(!schnorr_verify(
+            b"hellO",
+            &signature,
+            &signer.schnorr_public_key().unwrap()
+        ));
+    }
+
+    #[test]
+    fn should_sign_deterministically_with_seeded_rng() {
+        let signer = signer("B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF");
+        let first = signer
+            .schnorr_sign_with_rng(b"hello", &mut StdRng::seed_from_u64(7))
+            .unwrap();
+        let second = signer
+            .schnorr_sign_with_rng(b"hello", &mut StdRng::seed_from_u64(7))
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert!(schnorr_verify(
+            b"hello",
+            &first,
+            &signer.schnorr_public_key().unwrap()
+        ));
+    }
+
+    #[test]
+    fn should_reject_public_key_not_on_curve() {
+        // BIP340 test vector 5
+        let public_key =
+            from_hex("EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34");
+        let message =
+            from_hex::<32>("243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89");
+        let signature = from_hex("6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B");
+
+        assert!(!schnorr_verify(&message, &signature, &public_key));
+    }
+
+    #[test]
+    fn should_refuse_to_sign_with_zero_secret() {
+        let signer = Signature {
+            curve: Secp256k1,
+            secret: BigUint::ZERO,
+            public_key: CurvePoint::Infinity,
+        };
+        assert!(signer.schnorr_sign(b"hello").is_none());
+        assert!(signer.schnorr_public_key().is_none());
+    }
+
+    #[test]
+    fn should_derive_public_key_from_secret() {
+        let signer = signer("B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF");
+        let aux = [0_u8; 32];
+        let expected = signer.schnorr_sign_with_aux(b"hello", &aux);
+
+        // A cleared or mismatched `public_key` field is not trusted
+        for public_key in [CurvePoint::Infinity, Secp256k1.generator_point()] {
+            let stale = Signature {
+                curve: Secp256k1,
+                secret: signer.secret.clone(),
+                public_key,
+            };
+            assert_eq!(stale.schnorr_public_key(), signer.schnorr_public_key());
+            assert_eq!(stale.schnorr_sign_with_aux(b"hello", &aux), expected);
+        }
+    }
+
+    #[test]
+    fn should_share_x_only_key_with_negation() {
+        let point = Secp256k1.calculate_public_key(BigUint::from(3_u8));
+        let negated = negate_point(point.clone(), &Secp256k1);
+        let expected: [u8; 32] =
+            from_hex("F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9");
+
+        assert_eq!(x_only_public_key(&point), expected);
+        assert_eq!(x_only_public_key(&negated), expected);
+
+        let normalized = with_even_y(&point);
+        assert_eq!(with_even_y(&negated), normalized);
+        assert!(matches!(normalized, CurvePoint::Affine { y, .. } if !y.bit(0)));
+    }
+}
@@ -0,0 +1,46 @@
+//! Feeds arbitrary coordinates into `add_two_points` on the toy curve and checks the group law.
+//!
+//! Run with `cargo +nightly fuzz run add_two_points` from the repository root.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use num_bigint::BigUint;
+use signatures::elliptic_curves::{
+    arithmetic::add_two_points,
+    curve::{Curve, CurvePoint},
+    generic::GenericCurve,
+};
+
+/// Reads an `(x, y)` byte pair as a point, with an x of `0xff` standing for the point at infinity.
+///
+/// The coordinates are deliberately not reduced, so most pairs are off the curve.
+fn point(bytes: &[u8]) -> CurvePoint {
+    match bytes {
+        [0xff, _] => CurvePoint::Infinity,
+        [x, y] => CurvePoint::Affine {
+            x: BigUint::from(*x),
+            y: BigUint::from(*y),
+        },
+        _ => unreachable!("Points are read from byte pairs"),
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some(data) = data.get(..6) else {
+        return;
+    };
+    let curve = GenericCurve::toy();
+    let [p, q, r] = [&data[0..2], &data[2..4], &data[4..6]].map(point);
+    if ![&p, &q, &r].iter().all(|point| curve.is_on_curve(point)) {
+        return;
+    }
+
+    let sum = add_two_points(p.clone(), q.clone(), &curve);
+    assert!(curve.is_on_curve(&sum), "{p} + {q} = {sum} is off the curve");
+    assert_eq!(sum, add_two_points(q.clone(), p.clone(), &curve));
+    assert_eq!(
+        add_two_points(sum, r.clone(), &curve),
+        add_two_points(p, add_two_points(q, r, &curve), &curve)
+    );
+});